@@ -0,0 +1,33 @@
+//! Error type describing why parsing a number failed.
+use std::fmt;
+
+/// Error describing why parsing a number failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input is empty or contains whitespace only.
+    Empty,
+    /// The input doesn't contain a valid number.
+    InvalidDigit,
+    /// The number doesn't fit into the target type.
+    Overflow {
+        /// The number exceeds the upper bound, otherwise it falls below the lower one.
+        positive: bool,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse number from empty string"),
+            ParseError::InvalidDigit => write!(f, "invalid digit found in string"),
+            ParseError::Overflow { positive: true } => {
+                write!(f, "number too large to fit in target type")
+            }
+            ParseError::Overflow { positive: false } => {
+                write!(f, "number too small to fit in target type")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
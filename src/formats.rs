@@ -0,0 +1,673 @@
+//! Parsing of numbers in special, domain-specific formats.
+use super::*;
+use std::time::Duration;
+
+/// Internal function to parse an English ordinal number, like "1st" or "22nd".
+fn parse_ordinal_internal<
+    T: 'static
+        + num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::FromPrimitive
+        + num::ToPrimitive,
+>(
+    s: &str,
+    lenient: bool,
+) -> Option<T> {
+    let mut chars = s.chars().peekable();
+    let ret = parse_uint_from_iter_with_radix::<T>(&mut chars, Some(10), true)?;
+
+    if !chars.peek().is_some_and(|ch| ch.is_alphabetic()) {
+        return Some(ret);
+    }
+
+    let suffix = chars.take(2).collect::<String>().to_lowercase();
+
+    let last_digits = ret.mod_floor(&T::from_u32(100).unwrap()).to_u32().unwrap();
+
+    let expected = match last_digits {
+        11..=13 => "th",
+        n if n % 10 == 1 => "st",
+        n if n % 10 == 2 => "nd",
+        n if n % 10 == 3 => "rd",
+        _ => "th",
+    };
+
+    if suffix == expected || (lenient && ["st", "nd", "rd", "th"].contains(&suffix.as_str())) {
+        Some(ret)
+    } else {
+        None
+    }
+}
+
+/// Parse an English ordinal number from a &str, like "1st", "2nd", "3rd" or "21st".
+///
+/// The suffix is optional, but when present, it must match the number's last digits.
+pub fn parse_ordinal<
+    T: 'static
+        + num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::FromPrimitive
+        + num::ToPrimitive,
+>(
+    s: &str,
+) -> Option<T> {
+    parse_ordinal_internal(s, false)
+}
+
+/// Parse an English ordinal number from a &str, accepting any ordinal suffix.
+///
+/// Works like parse_ordinal(), but also accepts mismatched suffixes like "2st".
+pub fn parse_ordinal_lenient<
+    T: 'static
+        + num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::FromPrimitive
+        + num::ToPrimitive,
+>(
+    s: &str,
+) -> Option<T> {
+    parse_ordinal_internal(s, true)
+}
+
+/// Internal function to parse a measurement with an optional uncertainty, like "1.5±0.2".
+fn parse_measurement_internal<T: 'static + num::Float>(s: &str, required: bool) -> Option<(T, T)> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let value = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+
+    while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    let marked = match chars.peek() {
+        Some('±') => {
+            chars.next();
+            true
+        }
+        Some('+') => chars.by_ref().take(3).eq(['+', '/', '-']),
+        _ => false,
+    };
+
+    if marked {
+        let uncertainty = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+        return (uncertainty >= T::zero()).then_some((value, uncertainty));
+    }
+
+    if required {
+        None
+    } else {
+        Some((value, T::zero()))
+    }
+}
+
+/// Parse a measurement with an uncertainty from a &str, like "1.5±0.2" or "1.5 +/- 0.2".
+///
+/// Returns the value and its uncertainty, which is zero when not given.
+pub fn parse_measurement<T: 'static + num::Float>(s: &str) -> Option<(T, T)> {
+    parse_measurement_internal(s, false)
+}
+
+/// Parse a measurement with an uncertainty from a &str, requiring the uncertainty.
+///
+/// Works like parse_measurement(), but rejects values without uncertainty.
+pub fn parse_measurement_strict<T: 'static + num::Float>(s: &str) -> Option<(T, T)> {
+    parse_measurement_internal(s, true)
+}
+
+/// Parse an angle in degrees, minutes and seconds from a &str into decimal degrees.
+///
+/// Accepts notations like `40°26'46"N` or `40°26.5'`, where minutes and seconds are optional,
+/// and the primes `′` and `″` may be used as well. A trailing hemisphere letter of `S` or `W`
+/// makes the angle negative.
+pub fn parse_dms<T: 'static + num::Float>(s: &str) -> Option<T> {
+    let options = ParseOptions {
+        pos_sign: None,
+        ..Default::default()
+    };
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let sixty = T::from(60).unwrap();
+
+    let mut ret =
+        T::from(parse_uint_from_iter_internal::<u32>(&mut chars, Some(10), &options).ok()?)?;
+
+    if !matches!(chars.next(), Some('°')) {
+        return None;
+    }
+
+    let mut scale = T::one();
+
+    for markers in [['\'', '′'], ['"', '″']] {
+        if !chars.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            break;
+        }
+
+        let value = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+
+        if value >= sixty || !chars.next().is_some_and(|ch| markers.contains(&ch)) {
+            return None;
+        }
+
+        scale = scale * sixty;
+        ret = ret + value / scale;
+    }
+
+    while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    match chars.next().map(|ch| ch.to_ascii_uppercase()) {
+        Some('N' | 'E') | None => {}
+        Some('S' | 'W') => ret = -ret,
+        Some(_) => return None,
+    }
+
+    if chars.any(|ch| !ch.is_whitespace()) {
+        return None;
+    }
+
+    Some(ret)
+}
+
+/// A duration with a direction, as `Duration` can't be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedDuration {
+    /// The duration points into the past, or is subtracted.
+    pub negative: bool,
+    /// The absolute duration.
+    pub duration: Duration,
+}
+
+/// Internal function to parse a duration with a unit, like "1.5h".
+fn parse_duration_internal(s: &str, signed: bool) -> Option<SignedDuration> {
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let value = parse_float_from_iter_internal::<f64>(&mut chars, &ParseOptions::default()).ok()?;
+
+    while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    let mut unit = String::new();
+
+    while let Some(ch) = chars.peek().copied().filter(|ch| ch.is_alphabetic()) {
+        unit.push(ch);
+        chars.next();
+    }
+
+    if chars.any(|ch| !ch.is_whitespace()) {
+        return None;
+    }
+
+    let factor = match unit.as_str() {
+        "ns" => 1e-9,
+        "us" | "µs" => 1e-6,
+        "ms" => 1e-3,
+        "s" => 1.0,
+        "m" | "min" => 60.0,
+        "h" => 3600.0,
+        "d" => 86400.0,
+        _ => return None,
+    };
+
+    if value.is_sign_negative() && !signed {
+        return None;
+    }
+
+    Some(SignedDuration {
+        negative: value < 0.0,
+        duration: Duration::try_from_secs_f64(value.abs() * factor).ok()?,
+    })
+}
+
+/// Parse a duration with a unit from a &str, like "1.5h", "30 min" or "250ms".
+///
+/// Accepted units are `ns`, `us` (or `µs`), `ms`, `s`, `m` (or `min`), `h` and `d`.
+/// Nothing but whitespace may follow the unit.
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    Some(parse_duration_internal(s, false)?.duration)
+}
+
+/// Parse a signed duration with a unit from a &str, like "-1.5h" or "+30m".
+///
+/// Works like parse_duration(), but accepts a negative sign.
+pub fn parse_duration_signed(s: &str) -> Option<SignedDuration> {
+    parse_duration_internal(s, true)
+}
+
+/// Parse an ISO 8601 duration from a &str, like "PT1H30M45S" or "P1DT0.5S".
+///
+/// The components `W`, `D`, `H`, `M` and `S` are accepted in this order, with `H`, `M` and `S`
+/// following the `T`. Years and months are rejected, as they don't have a fixed length.
+pub fn parse_iso8601_duration(s: &str) -> Option<Duration> {
+    let options = ParseOptions {
+        whitespace: false,
+        pos_sign: None,
+        ..Default::default()
+    };
+    let mut units: &[(char, f64)] = &[('W', 604800.0), ('D', 86400.0)];
+    let mut chars = s.trim().chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+
+    if chars.next() != Some('P') {
+        return None;
+    }
+
+    let mut secs = 0.0;
+    let mut time = false;
+    let mut any = false;
+
+    while chars.peek().is_some() {
+        // The T requires at least one component of hours, minutes or seconds to follow
+        if !time && chars.peek() == Some(&'T') {
+            chars.next();
+            units = &[('H', 3600.0), ('M', 60.0), ('S', 1.0)];
+            time = true;
+            any = false;
+            continue;
+        }
+
+        let value = parse_float_from_iter_internal::<f64>(&mut chars, &options).ok()?;
+        let unit = chars.next()?;
+        let pos = units.iter().position(|(ch, _)| *ch == unit)?;
+
+        if value < 0.0 {
+            return None;
+        }
+
+        secs += value * units[pos].1;
+        units = &units[pos + 1..];
+        any = true;
+    }
+
+    if !any {
+        return None;
+    }
+
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// Parse a CSS hex color from a &str into its red, green and blue components.
+///
+/// Accepts the long form `"#1a2b3c"` and the short form `"#abc"`, which means `"#aabbcc"`.
+pub fn parse_hex_rgb(s: &str) -> Option<(u8, u8, u8)> {
+    let hex = s.strip_prefix('#')?;
+
+    if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let (width, scale) = match hex.len() {
+        3 => (1, 17),
+        6 => (2, 1),
+        _ => return None,
+    };
+
+    let component = |i: usize| {
+        parse_uint_with_radix::<u8>(&hex[i * width..(i + 1) * width], 16).map(|c| c * scale)
+    };
+
+    Some((component(0)?, component(1)?, component(2)?))
+}
+
+/// Internal function to parse a percentage, like "20%", and apply it to a base value.
+fn parse_percent_of_internal<T: 'static + num::Float>(
+    s: &str,
+    base: T,
+    absolute: bool,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let value = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+
+    while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    let ret = match chars.next() {
+        Some('%') => value * base / T::from(100).unwrap(),
+        None if absolute => return Some(value),
+        _ => return None,
+    };
+
+    while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    chars.peek().is_none().then_some(ret)
+}
+
+/// Parse a percentage from a &str, like "20%" or "-10 %", and apply it to `base`.
+///
+/// The percentage sign is required, so `parse_percent_of::<f64>("20%", 150.0)` is `Some(30.0)`.
+pub fn parse_percent_of<T: 'static + num::Float>(s: &str, base: T) -> Option<T> {
+    parse_percent_of_internal(s, base, false)
+}
+
+/// Parse a percentage from a &str and apply it to `base`, or parse an absolute value.
+///
+/// Works like parse_percent_of(), but a number without percentage sign is returned as is,
+/// so with a base of `150.0`, both `"20%"` and `"30"` are `Some(30.0)`.
+pub fn parse_percent_of_lenient<T: 'static + num::Float>(s: &str, base: T) -> Option<T> {
+    parse_percent_of_internal(s, base, true)
+}
+
+/// Internal function to parse the four octets of an IPv4 address, like "192.168.0.1".
+fn parse_ipv4_octets_internal(s: &str, strict: bool) -> Option<[u8; 4]> {
+    let mut chars = s.chars().peekable();
+    let mut octets = [0; 4];
+
+    for (i, octet) in octets.iter_mut().enumerate() {
+        if i > 0 && chars.next() != Some('.') {
+            return None;
+        }
+
+        let zero = chars.peek() == Some(&'0');
+        let (value, count) = parse_digits(&mut chars, 10)?;
+
+        if strict && zero && count > 1 {
+            return None;
+        }
+
+        *octet = u8::try_from(value).ok()?;
+    }
+
+    chars.peek().is_none().then_some(octets)
+}
+
+/// Parse the four octets of an IPv4 address from a &str, like "192.168.0.1".
+///
+/// Each octet must be in the range `0..=255`. Leading zeros are accepted and ignored,
+/// so `"010.0.0.1"` is `[10, 0, 0, 1]`.
+pub fn parse_ipv4_octets(s: &str) -> Option<[u8; 4]> {
+    parse_ipv4_octets_internal(s, false)
+}
+
+/// Parse the four octets of an IPv4 address from a &str, rejecting leading zeros.
+///
+/// Works like parse_ipv4_octets(), but rejects octets like `"010"`, which some
+/// implementations read as octal.
+pub fn parse_ipv4_octets_strict(s: &str) -> Option<[u8; 4]> {
+    parse_ipv4_octets_internal(s, true)
+}
+
+/// Validate a run of decimal digits by the Luhn algorithm, as used for credit card numbers.
+///
+/// Values above 9 aren't digits, and make the validation fail.
+#[allow(clippy::manual_is_multiple_of)] // is_multiple_of() requires Rust 1.87
+pub fn luhn(digits: &[u8]) -> bool {
+    if digits.iter().any(|&dig| dig > 9) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &dig)| match (i % 2, dig as u32 * 2) {
+            (0, _) => dig as u32,
+            (_, double) if double > 9 => double - 9,
+            (_, double) => double,
+        })
+        .sum();
+
+    sum % 10 == 0
+}
+
+/// Parse decimal uint values from a &str, validating their digits with a checksum algorithm.
+///
+/// `checksum` receives the digit values in order of appearance, including leading zeros,
+/// and returns whether they're valid. No sign or radix prefix is accepted.
+pub fn parse_int_with_checksum<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+    checksum: impl FnOnce(&[u8]) -> bool,
+) -> Option<T> {
+    let options = ParseOptions {
+        pos_sign: None,
+        ..Default::default()
+    };
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, false);
+    let mut digits = String::new();
+
+    if options.scan_digits(&mut chars, 10, |dig| digits.push(dig)) == 0
+        || !options.parse_suffix(&mut chars, &prefix)
+    {
+        return None;
+    }
+
+    let values: Vec<u8> = digits
+        .chars()
+        .map(|dig| dig.to_digit(10).unwrap() as u8)
+        .collect();
+
+    if !checksum(&values) {
+        return None;
+    }
+
+    parse_uint_with_radix(&digits, 10)
+}
+
+/// Parse decimal uint values from a &str, validating their Luhn check digit.
+///
+/// This is used for credit card numbers, like `"4539148803436467"`.
+pub fn parse_int_luhn<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+) -> Option<T> {
+    parse_int_with_checksum(s, luhn)
+}
+
+#[test]
+fn test_parse_ordinal() {
+    assert_eq!(parse_ordinal::<u32>("1st"), Some(1));
+    assert_eq!(parse_ordinal::<u32>("2nd"), Some(2));
+    assert_eq!(parse_ordinal::<u32>("3rd"), Some(3));
+    assert_eq!(parse_ordinal::<u32>("4th"), Some(4));
+    assert_eq!(parse_ordinal::<u32>("11th"), Some(11));
+    assert_eq!(parse_ordinal::<u32>("12th"), Some(12));
+    assert_eq!(parse_ordinal::<u32>("13th"), Some(13));
+    assert_eq!(parse_ordinal::<u32>(" 21st place"), Some(21));
+    assert_eq!(parse_ordinal::<u32>("112TH"), Some(112));
+    assert_eq!(parse_ordinal::<u32>("42"), Some(42));
+    assert_eq!(parse_ordinal::<u32>("2st"), None);
+    assert_eq!(parse_ordinal::<u32>("11st"), None);
+    assert_eq!(parse_ordinal::<u32>("1s"), None);
+    assert_eq!(parse_ordinal::<u32>("st"), None);
+
+    assert_eq!(parse_ordinal_lenient::<u32>("2st"), Some(2));
+    assert_eq!(parse_ordinal_lenient::<u32>("2xx"), None);
+}
+
+#[test]
+fn test_parse_measurement() {
+    assert_eq!(parse_measurement::<f64>("1.5±0.25"), Some((1.5, 0.25)));
+    assert_eq!(parse_measurement::<f64>("1.5+/-0.25"), Some((1.5, 0.25)));
+    assert_eq!(
+        parse_measurement::<f64>(" -1.5e3 ± 2 V"),
+        Some((-1500.0, 2.0))
+    );
+    assert_eq!(parse_measurement::<f64>("1.5"), Some((1.5, 0.0)));
+    assert_eq!(parse_measurement::<f64>("1.5 +/ 2"), Some((1.5, 0.0)));
+    assert_eq!(parse_measurement::<f64>("1.5±"), None);
+    assert_eq!(parse_measurement::<f64>("1.5±-0.25"), None);
+    assert_eq!(parse_measurement::<f64>("±0.25"), None);
+
+    assert_eq!(
+        parse_measurement_strict::<f64>("1.5±0.25"),
+        Some((1.5, 0.25))
+    );
+    assert_eq!(parse_measurement_strict::<f64>("1.5"), None);
+}
+
+#[test]
+fn test_parse_dms() {
+    let parse = |s: &str| parse_dms::<f64>(s).map(|deg| (deg * 1e6).round() / 1e6);
+
+    assert_eq!(parse("40°26'46\"N"), Some(40.446111));
+    assert_eq!(parse("79°58'56\" W"), Some(-79.982222));
+    assert_eq!(parse("33°52′4.5″S"), Some(-33.867917));
+    assert_eq!(parse(" 12°30'"), Some(12.5));
+    assert_eq!(parse("12°"), Some(12.0));
+    assert_eq!(parse("12°30'e"), Some(12.5));
+    assert_eq!(parse("12°60'"), None);
+    assert_eq!(parse("12°30"), None);
+    assert_eq!(parse("12°30'X"), None);
+    assert_eq!(parse("12°30'N 5"), None);
+    assert_eq!(parse("-12°"), None);
+    assert_eq!(parse("12"), None);
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("1.5h"), Some(Duration::from_secs(5400)));
+    assert_eq!(parse_duration(" 30 min "), Some(Duration::from_secs(1800)));
+    assert_eq!(parse_duration("250ms"), Some(Duration::from_millis(250)));
+    assert_eq!(parse_duration("2d"), Some(Duration::from_secs(172800)));
+    assert_eq!(parse_duration("-1.5h"), None);
+    assert_eq!(parse_duration("5"), None);
+    assert_eq!(parse_duration("5 parsecs"), None);
+    assert_eq!(parse_duration("5s later"), None);
+    assert_eq!(parse_duration("infh"), None);
+
+    assert_eq!(
+        parse_duration_signed("-1.5h"),
+        Some(SignedDuration {
+            negative: true,
+            duration: Duration::from_secs(5400)
+        })
+    );
+    assert_eq!(
+        parse_duration_signed("+30m"),
+        Some(SignedDuration {
+            negative: false,
+            duration: Duration::from_secs(1800)
+        })
+    );
+}
+
+#[test]
+fn test_parse_hex_rgb() {
+    assert_eq!(parse_hex_rgb("#1a2b3c"), Some((0x1a, 0x2b, 0x3c)));
+    assert_eq!(parse_hex_rgb("#FFffFF"), Some((255, 255, 255)));
+    assert_eq!(parse_hex_rgb("#abc"), Some((0xaa, 0xbb, 0xcc)));
+    assert_eq!(parse_hex_rgb("1a2b3c"), None);
+    assert_eq!(parse_hex_rgb("#1a2b3"), None);
+    assert_eq!(parse_hex_rgb("#1a2b3g"), None);
+    assert_eq!(parse_hex_rgb("#+1a2b3"), None);
+    assert_eq!(parse_hex_rgb("#"), None);
+}
+
+#[test]
+fn test_parse_percent_of() {
+    assert_eq!(parse_percent_of::<f64>("20%", 150.0), Some(30.0));
+    assert_eq!(parse_percent_of::<f64>(" -10 % ", 150.0), Some(-15.0));
+    assert_eq!(parse_percent_of::<f64>("+50%", -8.0), Some(-4.0));
+    assert_eq!(parse_percent_of::<f64>("30", 150.0), None);
+    assert_eq!(parse_percent_of::<f64>("20%x", 150.0), None);
+    assert_eq!(parse_percent_of::<f64>("%", 150.0), None);
+    assert_eq!(parse_percent_of_lenient::<f64>("20%", 150.0), Some(30.0));
+    assert_eq!(parse_percent_of_lenient::<f64>("30", 150.0), Some(30.0));
+    assert_eq!(parse_percent_of_lenient::<f64>("-30", 150.0), Some(-30.0));
+    assert_eq!(parse_percent_of_lenient::<f64>("30 ", 150.0), Some(30.0));
+    assert_eq!(parse_percent_of_lenient::<f64>("30x", 150.0), None);
+}
+
+#[test]
+fn test_parse_ipv4_octets() {
+    assert_eq!(parse_ipv4_octets("192.168.0.1"), Some([192, 168, 0, 1]));
+    assert_eq!(parse_ipv4_octets("255.255.255.255"), Some([255; 4]));
+    assert_eq!(parse_ipv4_octets("010.0.0.1"), Some([10, 0, 0, 1]));
+    assert_eq!(parse_ipv4_octets("256.0.0.1"), None);
+    assert_eq!(parse_ipv4_octets("1.2.3"), None);
+    assert_eq!(parse_ipv4_octets("1.2.3.4.5"), None);
+    assert_eq!(parse_ipv4_octets("1.2..4"), None);
+    assert_eq!(parse_ipv4_octets("1.2.3.4 "), None);
+    assert_eq!(parse_ipv4_octets("+1.2.3.4"), None);
+    assert_eq!(
+        parse_ipv4_octets_strict("192.168.0.1"),
+        Some([192, 168, 0, 1])
+    );
+    assert_eq!(parse_ipv4_octets_strict("010.0.0.1"), None);
+    assert_eq!(parse_ipv4_octets_strict("10.0.00.1"), None);
+}
+
+#[test]
+fn test_parse_int_luhn() {
+    assert_eq!(
+        parse_int_luhn::<u64>("4539148803436467"),
+        Some(4539148803436467)
+    );
+    assert_eq!(parse_int_luhn::<u64>(" 79927398713"), Some(79927398713));
+    assert_eq!(parse_int_luhn::<u64>("4539148803436468"), None);
+    assert_eq!(parse_int_luhn::<u64>("+79927398713"), None);
+    assert_eq!(parse_int_luhn::<u64>(""), None);
+    assert_eq!(parse_int_luhn::<u8>("4539148803436467"), None);
+    assert!(luhn(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3]));
+    assert!(!luhn(&[200, 0]));
+    assert!(!luhn(&[10]));
+
+    // ISBN-13 check digits alternate the weights 1 and 3
+    let isbn13 = |digits: &[u8]| {
+        digits.len() == 13
+            && digits
+                .iter()
+                .enumerate()
+                .map(|(i, &dig)| dig as u32 * if i % 2 == 0 { 1 } else { 3 })
+                .sum::<u32>()
+                % 10
+                == 0
+    };
+
+    assert_eq!(
+        parse_int_with_checksum::<u64>("9780306406157", isbn13),
+        Some(9780306406157)
+    );
+    assert_eq!(
+        parse_int_with_checksum::<u64>("9780306406158", isbn13),
+        None
+    );
+    assert_eq!(
+        parse_int_with_checksum::<u64>("0042", |digits| digits == [0, 0, 4, 2]),
+        Some(42)
+    );
+}
+
+#[test]
+fn test_parse_iso8601_duration() {
+    assert_eq!(
+        parse_iso8601_duration("PT1H30M45S"),
+        Some(Duration::from_secs(5445))
+    );
+    assert_eq!(
+        parse_iso8601_duration("PT0.5S"),
+        Some(Duration::from_millis(500))
+    );
+    assert_eq!(
+        parse_iso8601_duration("P1W2DT3M"),
+        Some(Duration::from_secs(777780))
+    );
+    assert_eq!(
+        parse_iso8601_duration("P1D"),
+        Some(Duration::from_secs(86400))
+    );
+    assert_eq!(parse_iso8601_duration("P1M"), None);
+    assert_eq!(parse_iso8601_duration("P1Y"), None);
+    assert_eq!(parse_iso8601_duration("PT1M1H"), None);
+    assert_eq!(parse_iso8601_duration("P1H"), None);
+    assert_eq!(parse_iso8601_duration("PT1D"), None);
+    assert_eq!(parse_iso8601_duration("PT-1S"), None);
+    assert_eq!(parse_iso8601_duration("PT"), None);
+    assert_eq!(parse_iso8601_duration("P"), None);
+    assert_eq!(parse_iso8601_duration("P1DT"), None);
+    assert_eq!(parse_iso8601_duration("PT1S2"), None);
+}
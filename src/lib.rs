@@ -4,15 +4,34 @@
 
 /*! num-parse
 
-    Generic, JavaScript-like parseInt() functions for Rust.
+    Generic, JavaScript-like parseInt() and parseFloat() functions for Rust.
 */
 
+mod error;
+mod formats;
+mod observe;
+mod options;
+mod parsefloat;
 mod parseint;
+pub use error::*;
+pub use formats::*;
+pub use observe::*;
+pub use options::*;
+pub use parsefloat::*;
 pub use parseint::*;
 
 /// Trait defining an iterator that implements a peek method on its own.
 pub trait PeekableIterator: std::iter::Iterator {
     fn peek(&mut self) -> Option<&Self::Item>;
+
+    /// Put back items consumed while looking ahead, so they are yielded again.
+    ///
+    /// Returns false when this isn't supported, and the items are lost. This is the case for
+    /// std::iter::Peekable, so use a Pushback to keep characters like the `e` of `"12ex"` in the
+    /// iterator.
+    fn put_back(&mut self, _items: &[Self::Item]) -> bool {
+        false
+    }
 }
 
 /// Implement PeekableIterator for all Peekable<Iterator>
@@ -21,3 +40,235 @@ impl<I: std::iter::Iterator> PeekableIterator for std::iter::Peekable<I> {
         std::iter::Peekable::peek(self)
     }
 }
+
+/// Peekable iterator over chars, which takes back any characters a parser looked ahead at.
+///
+/// Parsing from a Pushback leaves everything behind the number in the iterator, even when
+/// more than the one character a std::iter::Peekable can peek had to be read to find its end.
+pub struct Pushback<I: Iterator<Item = char>> {
+    buffer: Vec<char>, // in reverse order
+    chars: std::iter::Peekable<I>,
+}
+
+impl<I: Iterator<Item = char>> Pushback<I> {
+    pub fn new(chars: I) -> Self {
+        Self {
+            buffer: Vec::new(),
+            chars: chars.peekable(),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Pushback<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.buffer.pop().or_else(|| self.chars.next())
+    }
+}
+
+impl<I: Iterator<Item = char>> PeekableIterator for Pushback<I> {
+    fn peek(&mut self) -> Option<&char> {
+        match self.buffer.last() {
+            Some(ch) => Some(ch),
+            None => self.chars.peek(),
+        }
+    }
+
+    fn put_back(&mut self, items: &[char]) -> bool {
+        self.buffer.extend(items.iter().rev());
+        true
+    }
+}
+
+/// Internal iterator which replays already consumed characters in front of another iterator.
+///
+/// This allows for backtracking over more than the one character a PeekableIterator can peek.
+/// Characters still buffered when the Replay is dropped are put back into the other iterator.
+pub(crate) struct Replay<'a> {
+    buffer: Vec<char>, // in reverse order
+    chars: &'a mut dyn PeekableIterator<Item = char>,
+    consumed: usize,
+    read: usize,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(chars: &'a mut dyn PeekableIterator<Item = char>) -> Self {
+        Self {
+            buffer: Vec::new(),
+            chars,
+            consumed: 0,
+            read: 0,
+        }
+    }
+
+    /// Put back consumed characters, so they are yielded again.
+    pub fn unread(&mut self, chars: &[char]) {
+        self.buffer.extend(chars.iter().rev());
+        self.consumed -= chars.len();
+    }
+
+    /// Number of characters consumed and not put back.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Number of characters read from the underlying iterator, including those put back.
+    pub fn read(&self) -> usize {
+        self.read
+    }
+}
+
+impl Iterator for Replay<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = match self.buffer.pop() {
+            Some(ch) => ch,
+            None => {
+                let ch = self.chars.next()?;
+                self.read += 1;
+                ch
+            }
+        };
+
+        self.consumed += 1;
+        Some(ch)
+    }
+}
+
+impl PeekableIterator for Replay<'_> {
+    fn peek(&mut self) -> Option<&char> {
+        match self.buffer.last() {
+            Some(ch) => Some(ch),
+            None => self.chars.peek(),
+        }
+    }
+
+    fn put_back(&mut self, items: &[char]) -> bool {
+        self.unread(items);
+        true
+    }
+}
+
+impl Drop for Replay<'_> {
+    fn drop(&mut self) {
+        if !self.buffer.is_empty() {
+            self.buffer.reverse();
+            self.chars.put_back(&self.buffer);
+        }
+    }
+}
+
+/// Internal function to run a parser on a &str, returning its result and the remainder
+/// behind the parsed number.
+pub(crate) fn parse_with_rest<T>(
+    s: &str,
+    parse: impl FnOnce(&mut Replay) -> Result<T, ParseError>,
+) -> Result<(T, &str), ParseError> {
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let ret = parse(&mut chars)?;
+
+    Ok((ret, &s[byte_offset(s, chars.consumed())..]))
+}
+
+/// Internal function to get the byte offset of the character at index `chars` of a &str.
+pub(crate) fn byte_offset(s: &str, chars: usize) -> usize {
+    match s.char_indices().nth(chars) {
+        Some((offset, _)) => offset,
+        None => s.len(),
+    }
+}
+
+/// Extension trait providing the parse functions as methods on &str.
+///
+/// The methods delegate to the free functions of the same behavior, e.g.
+/// `"42".parse_int_lenient::<u32>()` is equivalent to `parse_int::<u32>("42")`.
+pub trait NumParseStrExt {
+    /// Parse decimal uint values, see parse_uint().
+    fn parse_uint_lenient<
+        T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+    >(
+        &self,
+    ) -> Option<T>;
+
+    /// Parse decimal int values, see parse_int().
+    fn parse_int_lenient<
+        T: 'static
+            + num::Integer
+            + num::CheckedAdd
+            + num::CheckedMul
+            + num::FromPrimitive
+            + num::Signed,
+    >(
+        &self,
+    ) -> Option<T>;
+
+    /// Parse float values, see parse_float().
+    fn parse_float_lenient<T: 'static + num::Float>(&self) -> Option<T>;
+}
+
+impl NumParseStrExt for str {
+    fn parse_uint_lenient<
+        T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+    >(
+        &self,
+    ) -> Option<T> {
+        parse_uint::<T>(self)
+    }
+
+    fn parse_int_lenient<
+        T: 'static
+            + num::Integer
+            + num::CheckedAdd
+            + num::CheckedMul
+            + num::FromPrimitive
+            + num::Signed,
+    >(
+        &self,
+    ) -> Option<T> {
+        parse_int::<T>(self)
+    }
+
+    fn parse_float_lenient<T: 'static + num::Float>(&self) -> Option<T> {
+        parse_float::<T>(self)
+    }
+}
+
+#[test]
+fn test_str_ext() {
+    assert_eq!("42".parse_uint_lenient::<u32>(), Some(42));
+    assert_eq!(" -42px".parse_int_lenient::<i32>(), Some(-42));
+    assert_eq!(" 2.5 ".parse_float_lenient::<f64>(), Some(2.5));
+    assert_eq!(String::from("x").parse_float_lenient::<f64>(), None);
+}
+
+#[test]
+fn test_pushback() {
+    let rest = |s: &str, parse: fn(&mut dyn PeekableIterator<Item = char>)| {
+        let mut chars = Pushback::new(s.chars());
+        parse(&mut chars);
+        chars.collect::<String>()
+    };
+
+    let float = |chars: &mut dyn PeekableIterator<Item = char>| {
+        parse_float_from_iter::<f64>(chars, true);
+    };
+
+    assert_eq!(rest("infinite", float), "inite");
+    assert_eq!(rest("12ex", float), "ex");
+    assert_eq!(rest("i+1", float), "i+1");
+    assert_eq!(rest("nan;", float), ";");
+
+    let int = |chars: &mut dyn PeekableIterator<Item = char>| {
+        parse_int_from_iter::<i32>(chars, true);
+    };
+
+    assert_eq!(rest(" -12ex", int), "ex");
+
+    // A std::iter::Peekable can't take back more than the peeked character
+    let mut chars = "12ex".chars().peekable();
+    assert_eq!(parse_float_from_iter::<f64>(&mut chars, true), Some(12.0));
+    assert_eq!(chars.collect::<String>(), "x");
+}
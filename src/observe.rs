@@ -0,0 +1,336 @@
+//! Observing the characters consumed while parsing, e.g. for syntax highlighting.
+//!
+//! Only the characters finally taken by the parser are reported, not those it just read ahead.
+//! As the parser may still put back characters until it is done, the callback isn't invoked
+//! while scanning, but once for each taken character after the parse returned, also when it
+//! failed. For the same reason, the callback can't stop the parse early: it returns nothing,
+//! rather than a `ControlFlow`, and the whole number is always scanned.
+//!
+//! The roles are classified from the consumed characters by the grammar of the number, not
+//! by the scanners themselves.
+use super::*;
+
+/// The role of a character consumed by a parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    /// Whitespace in front of the number.
+    Whitespace,
+    /// Sign of the number or of its exponent.
+    Sign,
+    /// Radix prefix, like the `x` in `0x`.
+    RadixPrefix,
+    /// Digit of the number or of its exponent.
+    Digit,
+    /// Decimal or grouping separator.
+    Separator,
+    /// Exponent marker, like `e` or `E`.
+    Exponent,
+    /// Anything else, like quotes or letters of `inf` and `NaN`.
+    Other,
+}
+
+/// Internal state of the Observer, reflecting the position in the number.
+#[derive(PartialEq)]
+enum State {
+    Start,
+    Int,
+    Frac,
+    Exp,
+}
+
+/// Internal iterator classifying any character consumed from the underlying iterator.
+///
+/// Characters put back by the parser are dropped from the classified characters again,
+/// so only those finally part of the number remain.
+struct Observer<'a> {
+    chars: &'a mut dyn PeekableIterator<Item = char>,
+    classified: Vec<(char, CharClass)>,
+    options: &'a ParseOptions,
+    radix: Option<u32>,
+    float: bool,
+    state: State,
+    digits: usize,
+}
+
+impl<'a> Observer<'a> {
+    fn new(
+        chars: &'a mut dyn PeekableIterator<Item = char>,
+        options: &'a ParseOptions,
+        radix: Option<u32>,
+        float: bool,
+    ) -> Self {
+        Self {
+            chars,
+            classified: Vec::new(),
+            options,
+            radix,
+            float,
+            state: State::Start,
+            digits: 0,
+        }
+    }
+
+    /// Report the characters kept by the parser to `on_char`.
+    fn report(self, mut on_char: impl FnMut(char, CharClass)) {
+        for (ch, class) in self.classified {
+            on_char(ch, class);
+        }
+    }
+
+    fn classify(&mut self, ch: char) -> CharClass {
+        let radix = self.radix.unwrap_or(10);
+
+        match self.state {
+            State::Start | State::Int => {
+                if self.state == State::Start && ch.is_whitespace() {
+                    CharClass::Whitespace
+                } else if self.state == State::Start
+                    && (ch == self.options.neg_sign || Some(ch) == self.options.pos_sign)
+                {
+                    CharClass::Sign
+                } else if let Some(prefixed) = self
+                    .options
+                    .radix_prefix(ch)
+                    .filter(|_| !self.float && self.radix.is_none() && self.digits == 1)
+                {
+                    self.radix = Some(prefixed);
+                    self.digits = 0;
+                    CharClass::RadixPrefix
+                } else if ch.is_digit(radix) {
+                    self.state = State::Int;
+                    self.digits += 1;
+                    CharClass::Digit
+                } else if self.state == State::Int && self.options.is_grouping(ch) {
+                    CharClass::Separator
+                } else if self.float && ch == self.options.decimal_separator {
+                    self.state = State::Frac;
+                    CharClass::Separator
+                } else if self.float && self.state == State::Int && (ch == 'e' || ch == 'E') {
+                    self.state = State::Exp;
+                    CharClass::Exponent
+                } else {
+                    CharClass::Other
+                }
+            }
+            State::Frac => {
+                if ch.is_ascii_digit() {
+                    CharClass::Digit
+                } else if ch == 'e' || ch == 'E' {
+                    self.state = State::Exp;
+                    CharClass::Exponent
+                } else {
+                    CharClass::Other
+                }
+            }
+            State::Exp => {
+                if ch.is_ascii_digit() {
+                    CharClass::Digit
+                } else if ch == '+' || ch == '-' {
+                    CharClass::Sign
+                } else {
+                    CharClass::Other
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for Observer<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.chars.next()?;
+        let class = self.classify(ch);
+        self.classified.push((ch, class));
+        Some(ch)
+    }
+}
+
+impl PeekableIterator for Observer<'_> {
+    fn peek(&mut self) -> Option<&char> {
+        self.chars.peek()
+    }
+
+    fn put_back(&mut self, items: &[char]) -> bool {
+        self.classified
+            .truncate(self.classified.len().saturating_sub(items.len()));
+        self.chars.put_back(items)
+    }
+}
+
+/// Parse uint values from an iterator with a given radix and options,
+/// calling `on_char` with the role of every consumed character after the parse.
+pub fn parse_uint_from_iter_observed<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    radix: Option<u32>,
+    options: &ParseOptions,
+    on_char: impl FnMut(char, CharClass),
+) -> Option<T> {
+    let mut chars = Observer::new(chars, options, radix, false);
+    let ret = parse_uint_from_iter_with_options(&mut chars, radix, options);
+    chars.report(on_char);
+    ret
+}
+
+/// Parse int values from an iterator with a given radix and options,
+/// calling `on_char` with the role of every consumed character after the parse.
+pub fn parse_int_from_iter_observed<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    radix: Option<u32>,
+    options: &ParseOptions,
+    on_char: impl FnMut(char, CharClass),
+) -> Option<T> {
+    let mut chars = Observer::new(chars, options, radix, false);
+    let ret = parse_int_from_iter_with_options(&mut chars, radix, options);
+    chars.report(on_char);
+    ret
+}
+
+/// Parse float values from an iterator with options,
+/// calling `on_char` with the role of every consumed character after the parse.
+pub fn parse_float_from_iter_observed<T: 'static + num::Float>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    options: &ParseOptions,
+    on_char: impl FnMut(char, CharClass),
+) -> Option<T> {
+    let mut chars = Observer::new(chars, options, None, true);
+    let ret = parse_float_from_iter_with_options(&mut chars, options);
+    chars.report(on_char);
+    ret
+}
+
+#[test]
+fn test_parse_observed() {
+    let options = ParseOptions::default();
+    let mut classes = Vec::new();
+
+    assert_eq!(
+        parse_float_from_iter_observed::<f64>(
+            &mut " -1.5e+3x".chars().peekable(),
+            &options,
+            |ch, class| classes.push((ch, class))
+        ),
+        Some(-1500.0)
+    );
+    assert_eq!(
+        classes,
+        vec![
+            (' ', CharClass::Whitespace),
+            ('-', CharClass::Sign),
+            ('1', CharClass::Digit),
+            ('.', CharClass::Separator),
+            ('5', CharClass::Digit),
+            ('e', CharClass::Exponent),
+            ('+', CharClass::Sign),
+            ('3', CharClass::Digit),
+        ]
+    );
+
+    let mut classes = Vec::new();
+
+    assert_eq!(
+        parse_int_from_iter_observed::<i64>(
+            &mut "-0xfe".chars().peekable(),
+            None,
+            &options,
+            |ch, class| classes.push((ch, class))
+        ),
+        Some(-254)
+    );
+    assert_eq!(
+        classes,
+        vec![
+            ('-', CharClass::Sign),
+            ('0', CharClass::Digit),
+            ('x', CharClass::RadixPrefix),
+            ('f', CharClass::Digit),
+            ('e', CharClass::Digit),
+        ]
+    );
+
+    let mut classes = Vec::new();
+
+    assert_eq!(
+        parse_uint_from_iter_observed::<u32>(
+            &mut "+10".chars().peekable(),
+            Some(2),
+            &options,
+            |ch, class| classes.push((ch, class))
+        ),
+        Some(2)
+    );
+    assert_eq!(
+        classes,
+        vec![
+            ('+', CharClass::Sign),
+            ('1', CharClass::Digit),
+            ('0', CharClass::Digit),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_observed_backtracking() {
+    let observe = |s: &str, options: &ParseOptions| {
+        let mut chars = Pushback::new(s.chars());
+        let mut classes = Vec::new();
+        let ret = parse_float_from_iter_observed::<f64>(&mut chars, options, |ch, class| {
+            classes.push((ch, class))
+        });
+
+        (ret, classes, chars.collect::<String>())
+    };
+
+    let digits = vec![('1', CharClass::Digit), ('2', CharClass::Digit)];
+    let options = ParseOptions::default();
+
+    assert_eq!(
+        observe("12ex", &options),
+        (Some(12.0), digits.clone(), "ex".to_string())
+    );
+    assert_eq!(
+        observe("12..5", &options),
+        (Some(12.0), digits.clone(), "..5".to_string())
+    );
+    assert_eq!(
+        observe("12.345", &Locale::DeDe.into()),
+        (
+            Some(12345.0),
+            vec![
+                ('1', CharClass::Digit),
+                ('2', CharClass::Digit),
+                ('.', CharClass::Separator),
+                ('3', CharClass::Digit),
+                ('4', CharClass::Digit),
+                ('5', CharClass::Digit),
+            ],
+            "".to_string()
+        )
+    );
+    assert_eq!(
+        observe("12.34", &Locale::DeDe.into()),
+        (Some(12.0), digits, ".34".to_string())
+    );
+}
+
+#[test]
+fn test_parse_observed_failure() {
+    let mut chars = Pushback::new(" -x".chars());
+    let mut classes = Vec::new();
+
+    assert_eq!(
+        parse_float_from_iter_observed::<f64>(&mut chars, &ParseOptions::default(), |ch, class| {
+            classes.push((ch, class))
+        }),
+        None
+    );
+    assert_eq!(
+        classes,
+        vec![(' ', CharClass::Whitespace), ('-', CharClass::Sign)]
+    );
+    assert_eq!(chars.collect::<String>(), "x");
+}
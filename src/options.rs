@@ -0,0 +1,1303 @@
+//! Options to configure the parsing behavior.
+use super::*;
+
+/// Allowed orderings of the sign and the currency symbol in front of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencySignOrder {
+    /// The sign precedes the currency symbol, like in `"-$5"`.
+    SignThenSymbol,
+    /// The currency symbol precedes the sign, like in `"$-5"`.
+    SymbolThenSign,
+    /// Both orderings are accepted.
+    Either,
+}
+
+/// Internal type of the function behind a GroupValidator.
+type ValidateGroups = dyn Fn(&[usize]) -> bool + Send + Sync;
+
+/// Validator of the sizes of digit groups, see [`ParseOptions::group_validator`].
+#[derive(Clone)]
+pub struct GroupValidator(std::sync::Arc<ValidateGroups>);
+
+impl GroupValidator {
+    pub fn new(valid: impl Fn(&[usize]) -> bool + Send + Sync + 'static) -> Self {
+        Self(std::sync::Arc::new(valid))
+    }
+}
+
+impl std::fmt::Debug for GroupValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("GroupValidator(..)")
+    }
+}
+
+/// Validators are only equal to their own clones, as closures can't be compared.
+impl PartialEq for GroupValidator {
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Group validator accepting groups of three digits behind a first group of one to three digits,
+/// like in `"1,234,567"`, for use with [`ParseOptions::group_validator`].
+pub fn groups_of_three(sizes: &[usize]) -> bool {
+    let Some((first, rest)) = sizes.split_first() else {
+        return false;
+    };
+
+    (1..=3).contains(first) && rest.iter().all(|size| *size == 3)
+}
+
+/// Group validator accepting the Indian grouping of lakhs and crores, like in `"12,34,567"`,
+/// for use with [`ParseOptions::group_validator`].
+pub fn indian_groups(sizes: &[usize]) -> bool {
+    let Some((last, rest)) = sizes.split_last() else {
+        return false;
+    };
+
+    *last == 3
+        && rest.first().is_none_or(|size| (1..=2).contains(size))
+        && rest.iter().skip(1).all(|size| *size == 2)
+}
+
+/// Handling of a Rust-like type suffix behind a number, like the `f32` in `"1.5f32"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeSuffix {
+    /// The suffix isn't recognized, and treated like any other trailing character.
+    Ignore,
+    /// A suffix is required, and must name the type parsed into.
+    Require,
+    /// A suffix is optional, but when present, it must name the type parsed into.
+    Validate,
+}
+
+/// Requirement on the sign of a float's exponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExponentSign {
+    /// The exponent may be signed, like in `"1e5"` and `"1e+5"`.
+    Optional,
+    /// The exponent must be signed, so `"1e5"` is rejected.
+    Required,
+    /// The exponent must not be signed, so `"1e+5"` and `"1e-5"` are rejected.
+    Forbidden,
+}
+
+/// Internal type of the function returning the TypeId named by a type suffix.
+type SuffixTypeId = fn() -> std::any::TypeId;
+
+/// Type suffixes recognized behind a number, with the type they name.
+const TYPE_SUFFIXES: [(&str, SuffixTypeId); 14] = [
+    ("f32", std::any::TypeId::of::<f32>),
+    ("f64", std::any::TypeId::of::<f64>),
+    ("i8", std::any::TypeId::of::<i8>),
+    ("i16", std::any::TypeId::of::<i16>),
+    ("i32", std::any::TypeId::of::<i32>),
+    ("i64", std::any::TypeId::of::<i64>),
+    ("i128", std::any::TypeId::of::<i128>),
+    ("isize", std::any::TypeId::of::<isize>),
+    ("u8", std::any::TypeId::of::<u8>),
+    ("u16", std::any::TypeId::of::<u16>),
+    ("u32", std::any::TypeId::of::<u32>),
+    ("u64", std::any::TypeId::of::<u64>),
+    ("u128", std::any::TypeId::of::<u128>),
+    ("usize", std::any::TypeId::of::<usize>),
+];
+
+/// Locale presets for the separators of a number.
+///
+/// All presets require a last group of three digits, so ambiguous inputs resolve by the locale:
+/// `"1,5"` is `1.5` in [`Locale::DeDe`], but `1` followed by trailing garbage in
+/// [`Locale::EnUs`], as `,5` isn't a valid group. Inversely, `"1.234"` is `1.234` in
+/// [`Locale::EnUs`] and `1234` in [`Locale::DeDe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// English (United States), like `"1,234.5"`.
+    EnUs,
+    /// German (Germany), like `"1.234,5"`.
+    DeDe,
+    /// Indian numbering with lakhs and crores, like `"12,34,567.5"`.
+    Indian,
+    /// Detect the separators of each float by a lookahead, like in `"1,234.5"` or `"1.234,5"`.
+    ///
+    /// Of `.` and `,`, the last separator is the decimal separator and must occur only once,
+    /// while the other one is the grouping separator with groups of three digits. Ambiguous
+    /// inputs are rejected: `"1,234"` may be either `1234` or `1.234`, and `"1,2,3"` has no
+    /// valid groups. A leading zero, like in `"0.125"`, is never grouped. Int values are
+    /// parsed without separators.
+    Auto,
+}
+
+/// Options to configure the parsing behavior.
+///
+/// Use struct update syntax to change single options, e.g.
+/// `ParseOptions { neg_sign: 'N', ..Default::default() }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOptions {
+    /// Skip whitespace in front of the number.
+    pub whitespace: bool,
+    /// Character introducing a negative number.
+    pub neg_sign: char,
+    /// Character introducing a positive number, `None` if no positive sign is accepted.
+    pub pos_sign: Option<char>,
+    /// Characters allowed to terminate a number, `None` to let any non-digit terminate it.
+    ///
+    /// When set, a number must be followed either by the end of input or by one of these
+    /// characters, so any other character makes the parse fail instead of truncating it.
+    pub terminators: Option<Vec<char>>,
+    /// Accept numbers enclosed by a matching pair of `"` or `'` quotes.
+    ///
+    /// Nothing but whitespace or a terminator may follow the closing quote.
+    pub quoted: bool,
+    /// Skip a leading byte order mark (U+FEFF) and zero-width characters along with whitespace.
+    pub skip_bom: bool,
+    /// Skip combining marks, like the OCR noise in `"\u{301}42"`, along with whitespace.
+    ///
+    /// This covers the Unicode blocks of combining diacritical marks, including their
+    /// extensions and supplements, and the combining marks for symbols and half marks.
+    pub skip_combining: bool,
+    /// Spellings accepted for infinity by the float parser, matched case-insensitively.
+    pub infinity: Vec<&'static str>,
+    /// Spellings accepted for NaN by the float parser, matched case-insensitively.
+    pub nan: Vec<&'static str>,
+    /// Reinterpret non-decimal int values filling the bit width of a signed type as two's
+    /// complement, so `"0xFF"` is parsed as `-1` into an `i8`.
+    ///
+    /// Values are limited to 128 bits when enabled, and negative values are not reinterpreted.
+    pub radix_twos_complement: bool,
+    /// Accept a trailing exponent on decimal int values, so `"5e3"` is parsed as `5000`.
+    ///
+    /// The exponent must consist of digits only; a signed exponent makes the parse fail.
+    pub int_exponent: bool,
+    /// Detect the radix from the prefixes `0o`, `0b` and `0d` as well, besides `0x`.
+    pub radix_prefixes: bool,
+    /// Accept radix-prefixed integers like `"0x10"` in the float parser, as `16.0`.
+    ///
+    /// The prefixes are detected like for ints. A fraction behind a radix prefix isn't
+    /// supported, as this would require hex-float support, so `"0x1.8"` is parsed as `1.0`.
+    pub prefixed_floats: bool,
+    /// Accept a decimal point without fraction digits behind an integer part, like in `"5."`.
+    ///
+    /// When disabled, such numbers are rejected instead of being parsed as `5.0`.
+    pub allow_trailing_dot: bool,
+    /// Maximum number of fraction digits accumulated by the float parser, `None` for all.
+    ///
+    /// Further fraction digits are consumed, but dropped.
+    pub max_frac_digits: Option<usize>,
+    /// Round the fraction on the first digit dropped by `max_frac_digits`, instead of truncating.
+    pub round_frac: bool,
+    /// Base the exponent of a float is applied to, so `"1.5e3"` means 1.5 × 2³ with base 2.
+    pub exponent_base: u32,
+    /// Grouping separator accepted between the digits of the integer part, like `,` or `'`.
+    ///
+    /// Whitespace like the thin space U+2009 is accepted as well. Between digits, it's taken as
+    /// separator even when whitespace is skipped, and it's only left behind the number when
+    /// it isn't followed by a valid group.
+    pub grouping: Option<char>,
+    /// Number of digits required in each group behind a grouping separator, `None` for any.
+    ///
+    /// When set, the first group may not be longer either. A grouping separator not followed
+    /// by a valid group isn't part of the number.
+    pub group_size: Option<usize>,
+    /// Accept single spaces as grouping separators, like in the French `"1 234 567"`.
+    ///
+    /// Besides the space, the no-break spaces U+00A0 and U+202F and the thin space U+2009 are
+    /// accepted. Like any grouping
+    /// separator, a space is only accepted between digits of the integer part, so `"3 . 14"` is
+    /// parsed as `3`.
+    pub space_grouping: bool,
+    /// Number of digits required in the last group, if it differs from `group_size`.
+    ///
+    /// This allows for patterns like the Indian `"12,34,567"`, where the last group has three
+    /// digits and all others have two.
+    pub last_group_size: Option<usize>,
+    /// Character separating the integer part and the fraction of a float.
+    ///
+    /// Parsing panics if this is also accepted as a grouping separator.
+    pub decimal_separator: char,
+    /// Currency symbol accepted once in front of the number, like `$` or `€`.
+    pub currency: Option<char>,
+    /// Allowed ordering of the sign and the currency symbol.
+    ///
+    /// Only one sign is accepted in any case, so `"-$-5"` is always rejected.
+    pub currency_sign_order: CurrencySignOrder,
+    /// Markup characters stripped around the number, like the `*` of the Markdown `"**5**"`.
+    ///
+    /// The markup must balance, so the same characters have to follow the number in reverse
+    /// order, and `"**5*"` or `"**5***"` are rejected.
+    pub markup: Vec<char>,
+    /// Fold a sequence of signs into one, like chained unary operators, so `"-+-5"` is `5`.
+    ///
+    /// When disabled, only a single sign is accepted and `"+-5"` is rejected.
+    pub fold_signs: bool,
+    /// Reject non-finite floats, both spelled out like `"inf"` or `"NaN"`, and values
+    /// overflowing to infinity, like `"1e999"` into an `f64`.
+    pub finite_only: bool,
+    /// Handling of a type suffix behind the number, like the `f64` in `"1.0f64"`.
+    ///
+    /// Only primitive types have a matching suffix, so any suffix is rejected for other types.
+    pub type_suffix: TypeSuffix,
+    /// Detect the decimal and grouping separators of each float among `.` and `,`,
+    /// as described for [`Locale::Auto`].
+    ///
+    /// The detected separators replace `grouping` and `decimal_separator`.
+    pub auto_separators: bool,
+    /// Reject int values of zero, including `"-0"` and `"+0"`, like for a quantity of at least 1.
+    pub reject_zero: bool,
+    /// Trailing markers giving the sign of a float, as a pair of the negative and the positive
+    /// marker, like `("CR", "DB")` for `"100.00CR"` being negative.
+    ///
+    /// The markers are matched case-insensitively, optionally behind whitespace, and must not be
+    /// followed by further letters. A number with both a sign and a marker is rejected.
+    pub sign_markers: Option<(&'static str, &'static str)>,
+    /// End the number in front of a decimal separator followed by whitespace, taking it as a
+    /// list separator instead, so the arguments of `"f(1,5, 2,5)"` are `1.5` and `2.5`.
+    pub spaced_separator_ends: bool,
+    /// Requirement on the sign of a float's exponent.
+    pub exponent_sign: ExponentSign,
+    /// Validator of the digit groups of the integer part, replacing `group_size` and
+    /// `last_group_size`, like `GroupValidator::new(groups_of_three)` or any closure.
+    ///
+    /// It receives the sizes of all groups in order, including the first one, and is only called
+    /// when there is a grouping separator. When the groups are invalid, the last group is dropped
+    /// from the number until they're valid, so the number may end in front of any separator.
+    pub group_validator: Option<GroupValidator>,
+    /// Maximum magnitude of a float's exponent, so `"1e999"` fails instead of becoming infinity.
+    ///
+    /// The exponent is checked as written, before it's applied to the digits.
+    pub max_exponent: Option<i32>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            whitespace: true,
+            neg_sign: '-',
+            pos_sign: Some('+'),
+            terminators: None,
+            quoted: false,
+            skip_bom: false,
+            skip_combining: false,
+            infinity: vec!["inf", "infinity"],
+            nan: vec!["nan"],
+            radix_twos_complement: false,
+            int_exponent: false,
+            radix_prefixes: false,
+            prefixed_floats: false,
+            allow_trailing_dot: true,
+            max_frac_digits: None,
+            round_frac: false,
+            exponent_base: 10,
+            grouping: None,
+            group_size: None,
+            space_grouping: false,
+            last_group_size: None,
+            decimal_separator: '.',
+            currency: None,
+            currency_sign_order: CurrencySignOrder::Either,
+            markup: Vec::new(),
+            fold_signs: false,
+            finite_only: false,
+            type_suffix: TypeSuffix::Ignore,
+            auto_separators: false,
+            reject_zero: false,
+            sign_markers: None,
+            spaced_separator_ends: false,
+            exponent_sign: ExponentSign::Optional,
+            group_validator: None,
+            max_exponent: None,
+        }
+    }
+}
+
+impl From<Locale> for ParseOptions {
+    fn from(locale: Locale) -> Self {
+        let (grouping, decimal_separator, group_size) = match locale {
+            Locale::EnUs => (',', '.', 3),
+            Locale::DeDe => ('.', ',', 3),
+            Locale::Indian => (',', '.', 2),
+            Locale::Auto => {
+                return Self {
+                    auto_separators: true,
+                    group_size: Some(3),
+                    last_group_size: Some(3),
+                    ..Default::default()
+                }
+            }
+        };
+
+        Self {
+            grouping: Some(grouping),
+            group_size: Some(group_size),
+            last_group_size: Some(3),
+            decimal_separator,
+            ..Default::default()
+        }
+    }
+}
+
+/// Internal information collected in front of a number.
+pub(crate) struct Prefix {
+    /// The number is negative.
+    pub neg: bool,
+    /// The number has a sign.
+    pub sign: bool,
+    /// The number is enclosed by this quote.
+    pub quote: Option<char>,
+    /// The number is enclosed by this markup, in order of appearance.
+    pub markup: Vec<char>,
+}
+
+impl ParseOptions {
+    /// Internal function to parse anything in front of a number, up to its sign.
+    ///
+    /// A negative sign is only accepted when `signed` is set.
+    pub(crate) fn parse_prefix(&self, chars: &mut Replay, signed: bool) -> Prefix {
+        assert!(
+            !self.is_grouping(self.decimal_separator),
+            "ParseOptions: decimal separator {:?} is also a grouping separator",
+            self.decimal_separator
+        );
+
+        let mut prefix = Prefix {
+            neg: false,
+            sign: false,
+            quote: None,
+            markup: Vec::new(),
+        };
+        let mut currency = false;
+
+        while let Some(ch) = chars.peek() {
+            if self.whitespace && ch.is_whitespace() {
+                chars.next();
+                continue;
+            }
+
+            if self.skip_bom && matches!(ch, '\u{feff}' | '\u{200b}'..='\u{200d}' | '\u{2060}') {
+                chars.next();
+                continue;
+            }
+
+            if self.skip_combining
+                && matches!(
+                    ch,
+                    '\u{300}'..='\u{36f}'
+                        | '\u{1ab0}'..='\u{1aff}'
+                        | '\u{1dc0}'..='\u{1dff}'
+                        | '\u{20d0}'..='\u{20ff}'
+                        | '\u{fe20}'..='\u{fe2f}'
+                )
+            {
+                chars.next();
+                continue;
+            }
+
+            if self.quoted && prefix.quote.is_none() && (*ch == '"' || *ch == '\'') {
+                prefix.quote = Some(*ch);
+                chars.next();
+                continue;
+            }
+
+            if !currency && self.markup.contains(ch) {
+                prefix.markup.push(*ch);
+                chars.next();
+                continue;
+            }
+
+            if !currency && self.currency == Some(*ch) {
+                currency = true;
+                chars.next();
+                continue;
+            }
+
+            if (signed && *ch == self.neg_sign) || Some(*ch) == self.pos_sign {
+                if currency && self.currency_sign_order == CurrencySignOrder::SignThenSymbol {
+                    break;
+                }
+
+                prefix.neg = *ch == self.neg_sign;
+                prefix.sign = true;
+                chars.next();
+
+                while let Some(ch) = chars.peek().copied().filter(|_| self.fold_signs) {
+                    if signed && ch == self.neg_sign {
+                        prefix.neg = !prefix.neg;
+                    } else if Some(ch) != self.pos_sign {
+                        break;
+                    }
+
+                    chars.next();
+                }
+
+                if !currency
+                    && self.currency_sign_order != CurrencySignOrder::SymbolThenSign
+                    && self.currency.is_some()
+                    && chars.peek() == self.currency.as_ref()
+                {
+                    chars.next();
+                }
+            }
+
+            break;
+        }
+
+        prefix
+    }
+
+    /// Internal function to detect the separators of a number by a lookahead.
+    ///
+    /// Returns the options with the detected separators, or None if they are ambiguous.
+    pub(crate) fn detect_separators(&self, chars: &mut Replay) -> Option<ParseOptions> {
+        let mut run = Vec::new();
+
+        while let Some(ch) = chars
+            .peek()
+            .copied()
+            .filter(|ch| ch.is_ascii_digit() || matches!(ch, '.' | ','))
+        {
+            run.push(ch);
+            chars.next();
+        }
+
+        chars.unread(&run);
+
+        // Only separators followed by a digit are within the number
+        while run.last().is_some_and(|ch| !ch.is_ascii_digit()) {
+            run.pop();
+        }
+
+        let separators: Vec<char> = run
+            .iter()
+            .copied()
+            .filter(|ch| !ch.is_ascii_digit())
+            .collect();
+        let groups: Vec<usize> = run
+            .split(|ch| !ch.is_ascii_digit())
+            .map(|group| group.len())
+            .collect();
+        // A leading zero can't start a group, so it forces a decimal separator
+        let valid_groups = |groups: &[usize]| {
+            run.first() != Some(&'0')
+                && (1..=3).contains(&groups[0])
+                && groups[1..].iter().all(|group| *group == 3)
+        };
+
+        let (grouping, decimal_separator) = match separators.split_last() {
+            None => (None, '.'),
+            Some((last, rest)) => {
+                let other = if *last == '.' { ',' } else { '.' };
+
+                if rest.is_empty() {
+                    // A single separator forming a valid group could be either one
+                    if valid_groups(&groups) {
+                        return None;
+                    }
+
+                    (Some(other), *last)
+                } else if rest.iter().all(|ch| ch == last) {
+                    if !valid_groups(&groups) {
+                        return None;
+                    }
+
+                    (Some(*last), other)
+                } else {
+                    if rest.contains(last) || !valid_groups(&groups[..groups.len() - 1]) {
+                        return None;
+                    }
+
+                    (Some(other), *last)
+                }
+            }
+        };
+
+        Some(ParseOptions {
+            grouping,
+            decimal_separator,
+            auto_separators: false,
+            ..self.clone()
+        })
+    }
+
+    /// Internal function to get the radix introduced by a prefix character behind a `0`.
+    pub(crate) fn radix_prefix(&self, ch: char) -> Option<u32> {
+        match ch {
+            'x' | 'X' => Some(16),
+            'o' | 'O' if self.radix_prefixes => Some(8),
+            'b' | 'B' if self.radix_prefixes => Some(2),
+            'd' | 'D' if self.radix_prefixes => Some(10),
+            _ => None,
+        }
+    }
+
+    /// Internal function to check for a grouping separator.
+    pub(crate) fn is_grouping(&self, ch: char) -> bool {
+        Some(ch) == self.grouping
+            || (self.space_grouping && matches!(ch, ' ' | '\u{a0}' | '\u{2009}' | '\u{202f}'))
+    }
+
+    /// Internal function to scan a run of digits with a given radix.
+    ///
+    /// Every digit taken is passed to `digit`, and the number of digits is returned.
+    /// Grouping separators are accepted between digits, and dropped from the result.
+    /// Groups are only taken once the group sizes are validated up to the last group,
+    /// so on a mismatch, the number ends in front of the first separator not yet validated.
+    /// Nothing is buffered unless a grouping separator is found.
+    pub(crate) fn scan_digits(
+        &self,
+        chars: &mut Replay,
+        radix: u32,
+        mut digit: impl FnMut(char),
+    ) -> usize {
+        let mut count = 0;
+
+        while let Some(dig) = chars.peek().copied().filter(|dig| dig.is_digit(radix)) {
+            digit(dig);
+            chars.next();
+            count += 1;
+        }
+
+        if count == 0 || !chars.peek().is_some_and(|ch| self.is_grouping(*ch)) {
+            return count;
+        }
+
+        if let Some(GroupValidator(valid)) = &self.group_validator {
+            return self.scan_groups_validated(chars, radix, count, digit, valid.as_ref());
+        }
+
+        let last_group_size = self.last_group_size.or(self.group_size);
+        let is_digit = |ch: Option<&char>| ch.is_some_and(|dig| dig.is_digit(radix));
+
+        let mut pending = Vec::new(); // groups not yet validated
+        let mut grouped = false;
+
+        while let Some(grouping) = chars.peek().copied().filter(|ch| self.is_grouping(*ch)) {
+            if !grouped && pending.is_empty() && self.group_size.is_some_and(|size| count > size) {
+                break;
+            }
+
+            chars.next();
+            pending.push(grouping);
+
+            let mut group = 0;
+
+            while is_digit(chars.peek()) {
+                if self.group_size.max(last_group_size) == Some(group) {
+                    break;
+                }
+
+                pending.push(chars.next().unwrap());
+                group += 1;
+            }
+
+            let is_last = match last_group_size {
+                Some(size) => group == size && !is_digit(chars.peek()),
+                None => group > 0,
+            };
+            let is_middle = self.group_size == Some(group)
+                && chars.peek().is_some_and(|ch| self.is_grouping(*ch));
+
+            if is_last {
+                for dig in pending.drain(..).filter(|ch| ch.is_digit(radix)) {
+                    digit(dig);
+                    count += 1;
+                }
+
+                grouped = true;
+
+                // A last group differing in size can't be followed by another group
+                if self.group_size.is_some_and(|size| size != group) {
+                    break;
+                }
+            } else if !is_middle {
+                break;
+            }
+        }
+
+        // Put back separators and groups not followed by a valid last group
+        chars.unread(&pending);
+
+        count
+    }
+
+    /// Internal function to scan the groups behind the first `count` digits, validating the group
+    /// sizes by `valid`.
+    fn scan_groups_validated(
+        &self,
+        chars: &mut Replay,
+        radix: u32,
+        mut count: usize,
+        mut digit: impl FnMut(char),
+        valid: &dyn Fn(&[usize]) -> bool,
+    ) -> usize {
+        let mut sizes = vec![count];
+        let mut pending = Vec::new(); // separators and groups behind the first group
+        let mut ends = vec![0]; // length of pending behind each group
+
+        while let Some(grouping) = chars.peek().copied().filter(|ch| self.is_grouping(*ch)) {
+            chars.next();
+            pending.push(grouping);
+
+            let mut size = 0;
+
+            while let Some(dig) = chars.peek().copied().filter(|dig| dig.is_digit(radix)) {
+                pending.push(dig);
+                chars.next();
+                size += 1;
+            }
+
+            if size == 0 {
+                break;
+            }
+
+            sizes.push(size);
+            ends.push(pending.len());
+        }
+
+        let groups = (2..=sizes.len())
+            .rev()
+            .find(|groups| valid(&sizes[..*groups]))
+            .unwrap_or(1);
+        let end = ends[groups - 1];
+
+        for dig in pending[..end].iter().filter(|ch| ch.is_digit(radix)) {
+            digit(*dig);
+            count += 1;
+        }
+
+        chars.unread(&pending[end..]);
+
+        count
+    }
+
+    /// Internal function to parse a trailing sign marker, as configured by `sign_markers`.
+    ///
+    /// Returns whether the marker is negative, or None without consuming anything if there's no
+    /// marker.
+    pub(crate) fn parse_sign_marker(&self, chars: &mut Replay) -> Option<bool> {
+        let (neg, pos) = self.sign_markers?;
+        let mut read = Vec::new();
+
+        while let Some(ch) = chars.peek().copied().filter(|ch| ch.is_whitespace()) {
+            read.push(ch);
+            chars.next();
+        }
+
+        let start = read.len();
+
+        while let Some(ch) = chars.peek().copied().filter(|ch| ch.is_alphabetic()) {
+            read.push(ch);
+            chars.next();
+        }
+
+        let marker = read[start..].iter().collect::<String>().to_lowercase();
+
+        if marker == neg.to_lowercase() {
+            Some(true)
+        } else if marker == pos.to_lowercase() {
+            Some(false)
+        } else {
+            chars.unread(&read);
+            None
+        }
+    }
+
+    /// Internal function to parse a type suffix behind a number, as configured by `type_suffix`.
+    ///
+    /// Returns false when the suffix is missing but required, or doesn't name `T`.
+    pub(crate) fn parse_type_suffix<T: 'static>(&self, chars: &mut Replay) -> bool {
+        if self.type_suffix == TypeSuffix::Ignore {
+            return true;
+        }
+
+        let mut read = Vec::new();
+
+        while let Some(ch) = chars
+            .peek()
+            .copied()
+            .filter(|ch| ch.is_ascii_alphanumeric())
+        {
+            read.push(ch);
+            chars.next();
+        }
+
+        let suffix = read.iter().collect::<String>();
+
+        if let Some((_, type_id)) = TYPE_SUFFIXES.iter().find(|(name, _)| *name == suffix) {
+            return type_id() == std::any::TypeId::of::<T>();
+        }
+
+        chars.unread(&read);
+        self.type_suffix != TypeSuffix::Require
+    }
+
+    /// Internal function to parse anything behind a number.
+    ///
+    /// Returns false when the number isn't validly terminated.
+    pub(crate) fn parse_suffix(&self, chars: &mut Replay, prefix: &Prefix) -> bool {
+        for markup in prefix.markup.iter().rev() {
+            if chars.next().as_ref() != Some(markup) {
+                return false;
+            }
+        }
+
+        if chars.peek().is_some_and(|ch| self.markup.contains(ch)) {
+            return false;
+        }
+
+        if let Some(quote) = prefix.quote {
+            if chars.next() != Some(quote) {
+                return false;
+            }
+
+            while self.whitespace && chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+                chars.next();
+            }
+
+            // Only a terminator or the end of input may follow the closing quote
+            return match (chars.peek(), &self.terminators) {
+                (None, _) => true,
+                (Some(ch), Some(terminators)) => terminators.contains(ch),
+                (Some(_), None) => false,
+            };
+        }
+
+        match (chars.peek(), &self.terminators) {
+            (Some(ch), Some(terminators)) => terminators.contains(ch),
+            _ => true,
+        }
+    }
+}
+
+#[test]
+fn test_skip_bom() {
+    let options = ParseOptions {
+        skip_bom: true,
+        ..Default::default()
+    };
+
+    assert_eq!(parse_float::<f64>("\u{feff}2.5"), None);
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "\u{feff}2.5".chars().peekable(), &options),
+        Some(2.5)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut " \u{200b}-42".chars().peekable(),
+            None,
+            &options
+        ),
+        Some(-42)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut "-\u{feff}42".chars().peekable(),
+            None,
+            &options
+        ),
+        None
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut "4\u{feff}2".chars().peekable(),
+            None,
+            &options
+        ),
+        Some(4)
+    );
+}
+
+#[test]
+fn test_skip_combining() {
+    let options = ParseOptions {
+        skip_combining: true,
+        ..Default::default()
+    };
+
+    assert_eq!(parse_int::<i32>("\u{301}42"), None);
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut " \u{301}\u{20dd} -42".chars().peekable(),
+            None,
+            &options
+        ),
+        Some(-42)
+    );
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "\u{fe20}2.5".chars().peekable(), &options),
+        Some(2.5)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut "-\u{301}42".chars().peekable(),
+            None,
+            &options
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_space_grouping() {
+    let options = ParseOptions {
+        space_grouping: true,
+        ..Default::default()
+    };
+
+    let parse =
+        |s: &str| parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &options);
+
+    assert_eq!(parse_float::<f64>("1 234 567"), Some(1.0));
+    assert_eq!(parse(" 1 234 567,5"), Some(1234567.0));
+    assert_eq!(parse("1\u{202f}234.5"), Some(1234.5));
+    assert_eq!(parse("1  234"), Some(1.0));
+    assert_eq!(parse("3 . 14"), Some(3.0));
+    assert_eq!(parse("1.5 234"), Some(1.5));
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut "-12 345".chars().peekable(),
+            None,
+            &ParseOptions {
+                group_size: Some(3),
+                ..options.clone()
+            }
+        ),
+        Some(-12345)
+    );
+}
+
+#[test]
+fn test_thin_space_grouping() {
+    for grouping in ['\u{2009}', '\u{202f}'] {
+        let options = ParseOptions {
+            grouping: Some(grouping),
+            quoted: true,
+            ..Default::default()
+        };
+        let parse = |s: &str| {
+            let mut chars = s.chars().peekable();
+            let mut chars = Replay::new(&mut chars);
+            let ret = parse_float_from_iter_internal::<f64>(&mut chars, &options).ok();
+            (ret, chars.collect::<String>())
+        };
+
+        assert_eq!(
+            parse(&format!("{grouping}1{grouping}234{grouping}567.5")),
+            (Some(1234567.5), "".to_string())
+        );
+        assert_eq!(
+            parse(&format!("1{grouping}234{grouping}")),
+            (Some(1234.0), grouping.to_string())
+        );
+        assert_eq!(
+            parse(&format!("1{grouping}234{grouping}x")),
+            (Some(1234.0), format!("{grouping}x"))
+        );
+        assert_eq!(
+            parse(&format!("'1{grouping}234'{grouping}")),
+            (Some(1234.0), "".to_string())
+        );
+    }
+
+    let options = ParseOptions {
+        space_grouping: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(
+            &mut "1\u{2009}234".chars().peekable(),
+            None,
+            &options
+        ),
+        Some(1234)
+    );
+}
+
+#[test]
+fn test_currency() {
+    let parse = |s: &str, currency_sign_order| {
+        parse_float_from_iter_with_options::<f64>(
+            &mut s.chars().peekable(),
+            &ParseOptions {
+                currency: Some('$'),
+                currency_sign_order,
+                ..Default::default()
+            },
+        )
+    };
+
+    for order in [
+        CurrencySignOrder::SignThenSymbol,
+        CurrencySignOrder::SymbolThenSign,
+        CurrencySignOrder::Either,
+    ] {
+        assert_eq!(parse("$5.00", order), Some(5.0));
+        assert_eq!(parse("5.00", order), Some(5.0));
+        assert_eq!(parse("-5.00", order), Some(-5.0));
+        assert_eq!(parse("-$-5", order), None);
+        assert_eq!(parse("$$5", order), None);
+    }
+
+    assert_eq!(
+        parse("-$5.00", CurrencySignOrder::SignThenSymbol),
+        Some(-5.0)
+    );
+    assert_eq!(parse("$-5.00", CurrencySignOrder::SignThenSymbol), None);
+    assert_eq!(parse("-$5.00", CurrencySignOrder::SymbolThenSign), None);
+    assert_eq!(
+        parse("$-5.00", CurrencySignOrder::SymbolThenSign),
+        Some(-5.0)
+    );
+    assert_eq!(parse("-$5.00", CurrencySignOrder::Either), Some(-5.0));
+    assert_eq!(parse("$-5.00", CurrencySignOrder::Either), Some(-5.0));
+    assert_eq!(parse(" $ -5.00", CurrencySignOrder::Either), Some(-5.0));
+}
+
+#[test]
+fn test_quoted() {
+    let options = ParseOptions {
+        quoted: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "\"1234.5\"".chars().peekable(), &options),
+        Some(1234.5)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut " '-42' ".chars().peekable(), None, &options),
+        Some(-42)
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "42".chars().peekable(), None, &options),
+        Some(42)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "\"42".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "\"42'".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "\"42\"x".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "\"\"42".chars().peekable(), None, &options),
+        None
+    );
+
+    let options = ParseOptions {
+        quoted: true,
+        terminators: Some(vec![',']),
+        ..Default::default()
+    };
+
+    let mut chars = "\"1\",\"2\"".chars().peekable();
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut chars, None, &options),
+        Some(1)
+    );
+    assert_eq!(chars.next(), Some(','));
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut chars, None, &options),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_markup() {
+    let options = ParseOptions {
+        markup: vec!['*', '_'],
+        ..Default::default()
+    };
+    let parse =
+        |s: &str| parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &options);
+
+    assert_eq!(parse("**5**"), Some(5.0));
+    assert_eq!(parse("*2.5*"), Some(2.5));
+    assert_eq!(parse("_*-2.5*_"), Some(-2.5));
+    assert_eq!(parse("2.5"), Some(2.5));
+    assert_eq!(parse("**5*"), None);
+    assert_eq!(parse("*5**"), None);
+    assert_eq!(parse("_*5_*"), None);
+    assert_eq!(parse("5*"), None);
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "**42**".chars().peekable(), None, &options),
+        Some(42)
+    );
+    assert_eq!(parse_float::<f64>("*5*"), None);
+}
+
+#[test]
+#[should_panic(expected = "decimal separator ',' is also a grouping separator")]
+fn test_separator_conflict() {
+    let options = ParseOptions {
+        grouping: Some(','),
+        decimal_separator: ',',
+        ..Default::default()
+    };
+
+    parse_float_from_iter_with_options::<f64>(&mut "1,5".chars().peekable(), &options);
+}
+
+#[test]
+fn test_fold_signs() {
+    let parse = |s: &str, fold_signs| {
+        parse_int_from_iter_with_options::<i32>(
+            &mut s.chars().peekable(),
+            None,
+            &ParseOptions {
+                fold_signs,
+                ..Default::default()
+            },
+        )
+    };
+
+    for (s, single, folded) in [
+        ("5", Some(5), Some(5)),
+        ("-5", Some(-5), Some(-5)),
+        ("+5", Some(5), Some(5)),
+        ("--5", None, Some(5)),
+        ("+-5", None, Some(-5)),
+        ("-+5", None, Some(-5)),
+        ("-+-5", None, Some(5)),
+        ("---5", None, Some(-5)),
+        ("++5", None, Some(5)),
+        ("- -5", None, None),
+        ("--", None, None),
+    ] {
+        assert_eq!(parse(s, false), single, "{s}");
+        assert_eq!(parse(s, true), folded, "{s}");
+    }
+
+    let options = ParseOptions {
+        fold_signs: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "--2.5".chars().peekable(), &options),
+        Some(2.5)
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "++5".chars().peekable(), None, &options),
+        Some(5)
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "+-5".chars().peekable(), None, &options),
+        None
+    );
+}
+
+#[test]
+fn test_group_validator() {
+    let parse = |s: &str, group_validator: GroupValidator| {
+        let mut chars = s.chars().peekable();
+        let mut chars = Replay::new(&mut chars);
+        let ret = parse_float_from_iter_internal::<f64>(
+            &mut chars,
+            &ParseOptions {
+                grouping: Some(','),
+                group_validator: Some(group_validator),
+                ..Default::default()
+            },
+        )
+        .ok();
+        (ret, chars.collect::<String>())
+    };
+
+    for (s, expected, rest) in [
+        ("1,234,567.5", Some(1234567.5), ""),
+        ("1234567", Some(1234567.0), ""),
+        ("1,234,56", Some(1234.0), ",56"),
+        ("1234,567", Some(1234.0), ",567"),
+        ("1,2", Some(1.0), ",2"),
+        ("1,", Some(1.0), ","),
+    ] {
+        assert_eq!(
+            parse(s, GroupValidator::new(groups_of_three)),
+            (expected, rest.to_string()),
+            "{s}"
+        );
+    }
+
+    for (s, expected, rest) in [
+        ("12,34,567.5", Some(1234567.5), ""),
+        ("1,23,456", Some(123456.0), ""),
+        ("1,234", Some(1234.0), ""),
+        ("1,234,567", Some(1234.0), ",567"),
+        ("123,456", Some(123.0), ",456"),
+    ] {
+        assert_eq!(
+            parse(s, GroupValidator::new(indian_groups)),
+            (expected, rest.to_string()),
+            "{s}"
+        );
+    }
+
+    assert!(!groups_of_three(&[]));
+    assert!(!indian_groups(&[]));
+    assert!(groups_of_three(&[3]));
+    assert!(indian_groups(&[3]));
+
+    // Chinese grouping by myriads, with a validator capturing the configured group size
+    let size = 4;
+    let myriads = GroupValidator::new(move |sizes: &[usize]| {
+        sizes[0] <= size && sizes[1..].iter().all(|group| *group == size)
+    });
+
+    assert_eq!(
+        parse("1,2345,6789", myriads.clone()),
+        (Some(123456789.0), "".to_string())
+    );
+    assert_eq!(
+        parse("1,234,567", myriads.clone()),
+        (Some(1.0), ",234,567".to_string())
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i64>(
+            &mut "-1,2345".chars().peekable(),
+            None,
+            &ParseOptions {
+                grouping: Some(','),
+                group_validator: Some(myriads.clone()),
+                ..Default::default()
+            }
+        ),
+        Some(-12345)
+    );
+
+    assert_eq!(Some(myriads.clone()), Some(myriads));
+    assert_ne!(
+        GroupValidator::new(groups_of_three),
+        GroupValidator::new(groups_of_three)
+    );
+}
+
+#[test]
+fn test_locale_auto() {
+    let options = ParseOptions::from(Locale::Auto);
+    let parse = |s: &str| {
+        parse_float_from_iter_internal::<f64>(&mut Replay::new(&mut s.chars().peekable()), &options)
+            .ok()
+    };
+
+    for (s, expected) in [
+        ("1234", Some(1234.0)),
+        ("1234.5", Some(1234.5)),
+        ("1234,5", Some(1234.5)),
+        ("1,5", Some(1.5)),
+        ("1.5e3", Some(1500.0)),
+        (",5", Some(0.5)),
+        ("1234,567", Some(1234.567)),
+        ("1,234.5", Some(1234.5)),
+        ("1.234,5", Some(1234.5)),
+        ("-1,234,567.89", Some(-1234567.89)),
+        ("1.234.567,89", Some(1234567.89)),
+        ("1,234,567", Some(1234567.0)),
+        ("1.234.567", Some(1234567.0)),
+        ("1234.", Some(1234.0)),
+        ("1,234.5 and more", Some(1234.5)),
+        ("inf", Some(f64::INFINITY)),
+        ("0.125", Some(0.125)),
+        ("0,125", Some(0.125)),
+        ("-0.125", Some(-0.125)),
+        ("01.250", Some(1.25)),
+        ("0.125,5", None),
+        // Ambiguous: grouping or decimal separator
+        ("1,234", None),
+        ("1.234", None),
+        ("-12.345", None),
+        ("1,234.", None),
+        // Inconsistent groups or separators
+        ("1,2,3", None),
+        ("1.2.3", None),
+        ("1,23,456", None),
+        ("1234,567,890", None),
+        ("1,234.5.6", None),
+        ("1.234,5,6", None),
+        ("1,2.5", None),
+        ("12345,678.5", None),
+    ] {
+        assert_eq!(parse(s), expected, "{s}");
+    }
+
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "1,234".chars().peekable(), None, &options),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_type_suffix() {
+    let options = |type_suffix| ParseOptions {
+        type_suffix,
+        ..Default::default()
+    };
+    let float = |s: &str, type_suffix| {
+        parse_float_from_iter_with_options::<f32>(&mut s.chars().peekable(), &options(type_suffix))
+    };
+    let int = |s: &str, type_suffix| {
+        parse_int_from_iter_with_options::<i32>(
+            &mut s.chars().peekable(),
+            None,
+            &options(type_suffix),
+        )
+    };
+
+    for (s, ignore, require, validate) in [
+        (
+            "-1337.0e-30f32",
+            Some(-1337.0e-30),
+            Some(-1337.0e-30),
+            Some(-1337.0e-30),
+        ),
+        ("1.0f64", Some(1.0), None, None),
+        ("1.0", Some(1.0), None, Some(1.0)),
+        ("1.0u8", Some(1.0), None, None),
+        ("1.0f32x", Some(1.0), None, Some(1.0)),
+        ("1.0float", Some(1.0), None, Some(1.0)),
+    ] {
+        assert_eq!(float(s, TypeSuffix::Ignore), ignore, "{s}");
+        assert_eq!(float(s, TypeSuffix::Require), require, "{s}");
+        assert_eq!(float(s, TypeSuffix::Validate), validate, "{s}");
+    }
+
+    assert_eq!(int("-42i32", TypeSuffix::Require), Some(-42));
+    assert_eq!(int("0xffi32", TypeSuffix::Require), Some(0xff));
+    assert_eq!(int("42i64", TypeSuffix::Validate), None);
+    assert_eq!(int("42", TypeSuffix::Validate), Some(42));
+    assert_eq!(
+        parse_int_from_iter_with_options::<i64>(
+            &mut "42isize".chars().peekable(),
+            None,
+            &options(TypeSuffix::Validate)
+        ),
+        None
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<usize>(
+            &mut "42usize".chars().peekable(),
+            None,
+            &options(TypeSuffix::Require)
+        ),
+        Some(42)
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u64>(
+            &mut "42u64".chars().peekable(),
+            None,
+            &options(TypeSuffix::Require)
+        ),
+        Some(42)
+    );
+
+    let mut chars = "1.5f32,2".chars().peekable();
+    assert_eq!(
+        parse_float_from_iter_with_options::<f32>(&mut chars, &options(TypeSuffix::Validate)),
+        Some(1.5)
+    );
+    assert_eq!(chars.next(), Some(','));
+}
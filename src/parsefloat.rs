@@ -0,0 +1,2651 @@
+//! JavaScript-style parseFloat-like parsing of numbers from strings in Rust.
+use super::*;
+
+/// Parts of a decimal number, as scanned by the float parser.
+///
+/// This allows to build exact values of arbitrary precision from a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecimalParts {
+    /// The number is negative.
+    pub negative: bool,
+    /// Digits of the integer part, as values from 0 to 9.
+    pub int_digits: Vec<u8>,
+    /// Digits of the fraction, as values from 0 to 9.
+    pub frac_digits: Vec<u8>,
+    /// Decimal exponent.
+    pub exp: i32,
+}
+
+/// Internal representation of a scanned decimal number.
+pub(crate) struct Decimal {
+    neg: bool,
+    int: String,
+    frac: String,
+    exp: i32,
+    /// A decimal point was present.
+    point: bool,
+    /// An exponent was present.
+    sci: bool,
+}
+
+impl Decimal {
+    /// Convert the decimal into a float, with the exponent applied to the given base.
+    ///
+    /// The conversion is performed by the type's `from_str_radix()` on a canonical
+    /// representation, so it is correctly rounded and independent of the type's precision.
+    /// Only trait methods are used, so no IEEE 754 representation is assumed.
+    /// For bases other than 10, the mantissa is converted that way and then scaled.
+    fn to_float<T: num::Float>(&self, exp_base: u32) -> Option<T> {
+        let canonical = format!(
+            "{}{}.{}e{}",
+            if self.neg { "-" } else { "" },
+            if self.int.is_empty() { "0" } else { &self.int },
+            if self.frac.is_empty() {
+                "0"
+            } else {
+                &self.frac
+            },
+            if exp_base == 10 { self.exp } else { 0 }
+        );
+
+        let ret = T::from_str_radix(&canonical, 10).ok()?;
+
+        if exp_base == 10 || ret.is_zero() {
+            Some(ret)
+        } else {
+            Some(ret * T::from(exp_base)?.powi(self.exp))
+        }
+    }
+
+    /// Convert the decimal into its exact value, as a rational number.
+    fn to_ratio(&self) -> num::BigRational {
+        let mantissa = format!("0{}{}", self.int, self.frac)
+            .parse::<num::BigInt>()
+            .unwrap();
+        let shift = self.exp as i64 - self.frac.len() as i64;
+        let scale = num::pow(num::BigInt::from(10), shift.unsigned_abs() as usize);
+
+        let ret = if shift < 0 {
+            num::BigRational::new(mantissa, scale)
+        } else {
+            num::BigRational::from_integer(mantissa * scale)
+        };
+
+        if self.neg {
+            -ret
+        } else {
+            ret
+        }
+    }
+
+    /// Convert the decimal into an integer, rounded by `mode`, or only if it is integral.
+    fn to_int<
+        T: num::Integer + num::CheckedAdd + num::CheckedMul + num::CheckedSub + num::FromPrimitive,
+    >(
+        &self,
+        mode: Option<RoundingMode>,
+    ) -> Option<T> {
+        let digits = format!("{}{}", self.int, self.frac);
+        let digits = digits.trim_start_matches('0');
+        let mut shift = self.exp as i64 - self.frac.len() as i64;
+        let mut away = false; // round away from zero
+
+        // Strip digits behind the decimal point, which must all be zero unless rounded
+        let digits = if shift < 0 {
+            let places = shift.unsigned_abs() as usize;
+            let cut = digits.len().saturating_sub(places);
+            let dropped = &digits[cut..];
+
+            if dropped.chars().any(|ch| ch != '0') {
+                away = match mode {
+                    None => return None,
+                    Some(RoundingMode::Trunc) => false,
+                    Some(RoundingMode::Floor) => self.neg,
+                    Some(RoundingMode::Ceil) => !self.neg,
+                    Some(RoundingMode::Nearest) => dropped.len() == places && dropped >= "5",
+                };
+            }
+
+            shift = 0;
+            &digits[..cut]
+        } else {
+            digits
+        };
+
+        let mut ret = T::zero();
+        let ten = T::from_u32(10).unwrap();
+
+        // Negative values are accumulated negatively, so the minimum of a signed type is reached
+        for digit in digits.chars() {
+            let digit = T::from_u32(digit.to_digit(10).unwrap()).unwrap();
+
+            ret = ret.checked_mul(&ten)?;
+            ret = if self.neg {
+                ret.checked_sub(&digit)?
+            } else {
+                ret.checked_add(&digit)?
+            };
+        }
+
+        while shift > 0 && !ret.is_zero() {
+            ret = ret.checked_mul(&ten)?;
+            shift -= 1;
+        }
+
+        match away {
+            true if self.neg => ret.checked_sub(&T::one()),
+            true => ret.checked_add(&T::one()),
+            false => Some(ret),
+        }
+    }
+}
+
+/// Internal function to peek at the character behind the next one.
+fn peek_second(chars: &mut Replay) -> Option<char> {
+    let first = chars.next()?;
+    let second = chars.peek().copied();
+    chars.unread(&[first]);
+    second
+}
+
+/// Internal function to scan a decimal number with optional fraction and exponent.
+fn scan_decimal(chars: &mut Replay, neg: bool, options: &ParseOptions) -> Option<Decimal> {
+    let mut int = String::new();
+    options.scan_digits(chars, 10, |dig| int.push(dig));
+    let mut frac = String::new();
+    let mut frac_digits = 0;
+    let mut dropped = None;
+    let mut point = false;
+
+    // Grouping separators are only accepted in the integer part. A doubled decimal separator,
+    // like the range operator in "1..5", isn't part of the number.
+    if chars.peek() == Some(&options.decimal_separator)
+        && peek_second(chars).is_none_or(|ch| {
+            ch != options.decimal_separator
+                && !(options.spaced_separator_ends && ch.is_whitespace())
+        })
+    {
+        chars.next();
+        point = true;
+
+        while let Some(ch) = chars.peek().copied() {
+            if !ch.is_ascii_digit() {
+                break;
+            }
+
+            if options.max_frac_digits.is_none_or(|max| frac_digits < max) {
+                frac.push(ch);
+            } else if dropped.is_none() {
+                dropped = Some(ch);
+            }
+
+            frac_digits += 1;
+            chars.next();
+        }
+
+        if frac_digits == 0 && !options.allow_trailing_dot {
+            return None;
+        }
+    }
+
+    if int.is_empty() && frac_digits == 0 {
+        return None;
+    }
+
+    // Round half up on the first dropped fraction digit
+    if options.round_frac && dropped.is_some_and(|dig| dig >= '5') {
+        let mut digits: Vec<char> = format!("{}{}", int, frac).chars().collect();
+        let mut pos = digits.len();
+
+        while pos > 0 && digits[pos - 1] == '9' {
+            digits[pos - 1] = '0';
+            pos -= 1;
+        }
+
+        if pos > 0 {
+            digits[pos - 1] = (digits[pos - 1] as u8 + 1) as char;
+        } else {
+            digits.insert(0, '1');
+        }
+
+        frac = digits[digits.len() - frac.len()..].iter().collect();
+        int = digits[..digits.len() - frac.len()].iter().collect();
+    }
+
+    let mut exp = 0i32;
+    let mut sci = false;
+
+    if let Some(marker @ ('e' | 'E')) = chars.peek().copied() {
+        chars.next();
+
+        let mut read = vec![marker];
+        let mut exp_neg = false;
+
+        if let Some(ch) = chars.peek().copied() {
+            if ch == '+' || ch == '-' {
+                exp_neg = ch == '-';
+                chars.next();
+                read.push(ch);
+            }
+        }
+
+        while let Some(dig) = chars.peek() {
+            match dig.to_digit(10) {
+                Some(digit) => {
+                    exp = exp.saturating_mul(10).saturating_add(digit as i32);
+                    chars.next();
+                    sci = true;
+                }
+                None => break,
+            }
+        }
+
+        // An exponent without digits is not part of the number
+        if !sci {
+            chars.unread(&read);
+        } else if matches!(
+            (options.exponent_sign, read.len() > 1),
+            (ExponentSign::Required, false) | (ExponentSign::Forbidden, true)
+        ) || options.max_exponent.is_some_and(|max| exp > max)
+        {
+            return None;
+        }
+
+        if exp_neg {
+            exp = -exp;
+        }
+    }
+
+    Some(Decimal {
+        neg,
+        int,
+        frac,
+        exp,
+        point,
+        sci,
+    })
+}
+
+/// Internal function to match a spelling against the characters read, ignoring case.
+///
+/// Returns None when the spelling doesn't start with them, otherwise whether it's complete.
+fn match_spelling(spelling: &str, read: impl Iterator<Item = char>) -> Option<bool> {
+    let mut spelling = spelling.chars().flat_map(char::to_lowercase);
+
+    for ch in read.flat_map(char::to_lowercase) {
+        if spelling.next() != Some(ch) {
+            return None;
+        }
+    }
+
+    Some(spelling.next().is_none())
+}
+
+/// Internal function to scan one of the configured spellings for infinity or NaN.
+///
+/// The longest matching spelling is taken; on mismatch, all characters are put back.
+/// Only the spelling itself is consumed, so NaN payloads like `"NaN(1)"` are not supported.
+fn scan_special<T: num::Float>(chars: &mut Replay, neg: bool, options: &ParseOptions) -> Option<T> {
+    let spellings = || {
+        let infinity = options
+            .infinity
+            .iter()
+            .map(|spelling| (spelling, T::infinity()));
+        infinity.chain(options.nan.iter().map(|spelling| (spelling, T::nan())))
+    };
+
+    let mut read = Vec::new();
+    let mut matched = None;
+
+    while let Some(ch) = chars.peek().copied() {
+        let mut complete = None;
+        let mut partial = false;
+
+        for (spelling, value) in spellings() {
+            match match_spelling(spelling, read.iter().copied().chain([ch])) {
+                Some(true) => complete = complete.or(Some(value)),
+                Some(false) => partial = true,
+                None => {}
+            }
+        }
+
+        if complete.is_none() && !partial {
+            break;
+        }
+
+        read.push(ch);
+        chars.next();
+
+        if let Some(value) = complete {
+            matched = Some((value, read.len()));
+        }
+    }
+
+    match matched {
+        Some((value, len)) => {
+            chars.unread(&read[len..]);
+            Some(if neg { -value } else { value })
+        }
+        None => {
+            chars.unread(&read);
+            None
+        }
+    }
+}
+
+/// Internal function to scan a radix-prefixed integer like "0x10" as float, if enabled.
+///
+/// Returns None without consuming anything when there's no radix prefix.
+fn scan_prefixed<T: num::Float>(
+    chars: &mut Replay,
+    neg: bool,
+    options: &ParseOptions,
+) -> Option<Result<T, ParseError>> {
+    if !options.prefixed_floats || chars.peek() != Some(&'0') {
+        return None;
+    }
+
+    chars.next();
+
+    let Some(radix) = chars.peek().and_then(|ch| options.radix_prefix(*ch)) else {
+        chars.unread(&['0']);
+        return None;
+    };
+
+    chars.next();
+
+    let mut digits = String::new();
+
+    if options.scan_digits(chars, radix, |dig| digits.push(dig)) == 0 {
+        return Some(Err(ParseError::InvalidDigit));
+    }
+
+    Some(
+        T::from_str_radix(&digits, radix)
+            .map(|ret| if neg { -ret } else { ret })
+            .map_err(|_| ParseError::InvalidDigit),
+    )
+}
+
+/// Internal function to scan float values from an iterator with options.
+///
+/// Returns the value and the scanned decimal, which is None for infinity, NaN and
+/// radix-prefixed integers.
+pub(crate) fn scan_float<T: 'static + num::Float>(
+    chars: &mut Replay,
+    options: &ParseOptions,
+) -> Result<(T, Option<Decimal>), ParseError> {
+    let prefix = options.parse_prefix(chars, true);
+    let detected;
+    let options = if options.auto_separators {
+        detected = options
+            .detect_separators(chars)
+            .ok_or(ParseError::InvalidDigit)?;
+        &detected
+    } else {
+        options
+    };
+
+    let mut ret: (T, _) = if let Some(ret) = scan_special(chars, prefix.neg, options) {
+        if options.finite_only {
+            return Err(ParseError::InvalidDigit);
+        }
+
+        (ret, None)
+    } else if let Some(ret) = scan_prefixed(chars, prefix.neg, options) {
+        (ret?, None)
+    } else {
+        let decimal = scan_decimal(chars, prefix.neg, options).ok_or(ParseError::InvalidDigit)?;
+        (
+            decimal
+                .to_float(options.exponent_base)
+                .ok_or(ParseError::InvalidDigit)?,
+            Some(decimal),
+        )
+    };
+
+    if let Some(neg) = options.parse_sign_marker(chars) {
+        if prefix.sign {
+            return Err(ParseError::InvalidDigit);
+        }
+
+        if neg {
+            ret.0 = -ret.0;
+        }
+    }
+
+    if options.finite_only && !ret.0.is_finite() {
+        return Err(ParseError::Overflow {
+            positive: ret.0.is_sign_positive(),
+        });
+    }
+
+    if !options.parse_type_suffix::<T>(chars) || !options.parse_suffix(chars, &prefix) {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    Ok(ret)
+}
+
+/// Internal function to parse float values from an iterator with options.
+pub(crate) fn parse_float_from_iter_internal<T: 'static + num::Float>(
+    chars: &mut Replay,
+    options: &ParseOptions,
+) -> Result<T, ParseError> {
+    Ok(scan_float(chars, options)?.0)
+}
+
+/// Parse float values from an iterator with options.
+///
+/// Whitespace is skipped in front of the sign only, if enabled by the options.
+/// Characters read ahead behind the number, like the `e` of `"12ex"`, are only kept in
+/// iterators supporting PeekableIterator::put_back(), like a Pushback.
+pub fn parse_float_from_iter_with_options<T: 'static + num::Float>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    options: &ParseOptions,
+) -> Option<T> {
+    parse_float_from_iter_internal(&mut Replay::new(chars), options).ok()
+}
+
+/// Parse float values from an iterator.
+pub fn parse_float_from_iter<T: 'static + num::Float>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    whitespace: bool,
+) -> Option<T> {
+    parse_float_from_iter_with_options(
+        chars,
+        &ParseOptions {
+            whitespace,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse float values from a &str.
+///
+/// No intermediate of fixed precision is involved, so the result is correctly rounded
+/// for any type implementing `num::Float`, regardless of its width.
+pub fn parse_float<T: 'static + num::Float>(s: &str) -> Option<T> {
+    try_parse_float(s).ok()
+}
+
+/// Parse float values from chunks of &str, like they are received from a stream.
+///
+/// The chunks are parsed as one continuous text, so a number may straddle chunk boundaries.
+pub fn parse_float_from_chunks<'a, T: 'static + num::Float>(
+    chunks: impl IntoIterator<Item = &'a str>,
+) -> Option<T> {
+    parse_float_from_iter::<T>(
+        &mut chunks.into_iter().flat_map(str::chars).peekable(),
+        true,
+    )
+}
+
+/// Parse float values from a &str, capping the fraction to at most `digits` digits.
+///
+/// The fraction is rounded half up when `round` is set, and truncated otherwise.
+/// The remaining fraction digits are consumed, but not accumulated.
+pub fn parse_float_capped<T: 'static + num::Float>(
+    s: &str,
+    digits: usize,
+    round: bool,
+) -> Option<T> {
+    parse_float_from_iter_with_options::<T>(
+        &mut s.chars().peekable(),
+        &ParseOptions {
+            max_frac_digits: Some(digits),
+            round_frac: round,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse float values from a &str, rejecting numbers longer than `max_len` characters.
+///
+/// Whitespace in front of the number is skipped and doesn't count. Anything else read counts,
+/// including signs, separators and the exponent, and characters only looked ahead, like the
+/// `e` of `"1ex"`. No more than `max_len + 1` characters of the number are read from `s`,
+/// regardless of its length.
+pub fn parse_float_bounded_len<T: 'static + num::Float>(s: &str, max_len: usize) -> Option<T> {
+    let mut chars = s
+        .trim_start()
+        .chars()
+        .take(max_len.saturating_add(1))
+        .peekable();
+    let mut chars = Replay::new(&mut chars);
+    let ret = parse_float_from_iter_internal::<T>(&mut chars, &ParseOptions::default()).ok()?;
+
+    (chars.read() <= max_len).then_some(ret)
+}
+
+/// Parse float values from a &str, detecting the decimal and grouping separators by position.
+///
+/// Of `.` and `,`, the last separator within the number is considered the decimal separator,
+/// and the other one the grouping separator, so both `"1.234,56"` and `"1,234.56"` are parsed
+/// as `1234.56`. A separator occurring more than once is always the grouping separator, so
+/// `"1,234,567"` is `1234567.0`. Consequently, a single separator is always the decimal one,
+/// and `"1,234"` is parsed as `1.234`.
+pub fn parse_float_smart<T: 'static + num::Float>(s: &str) -> Option<T> {
+    let run: Vec<char> = s
+        .trim_start()
+        .trim_start_matches(['+', '-'])
+        .chars()
+        .take_while(|ch| ch.is_ascii_digit() || *ch == '.' || *ch == ',')
+        .collect();
+
+    // Only separators followed by a digit are within the number
+    let last = run
+        .windows(2)
+        .rev()
+        .find(|pair| !pair[0].is_ascii_digit() && pair[1].is_ascii_digit())
+        .map(|pair| pair[0]);
+
+    let (decimal_separator, grouping) = match last {
+        Some(last) if run.iter().filter(|ch| **ch == last).count() == 1 => {
+            (last, if last == '.' { ',' } else { '.' })
+        }
+        Some(last) => (if last == '.' { ',' } else { '.' }, last),
+        None => ('.', ','),
+    };
+
+    parse_float_from_iter_with_options(
+        &mut s.chars().peekable(),
+        &ParseOptions {
+            grouping: Some(grouping),
+            decimal_separator,
+            ..Default::default()
+        },
+    )
+}
+
+/// Parse float values from a &str, reporting the reason on failure.
+pub fn try_parse_float<T: 'static + num::Float>(s: &str) -> Result<T, ParseError> {
+    parse_float_full(s)
+        .map(|(ret, _)| ret)
+        .map_err(|(err, _)| err)
+}
+
+/// Parse float values from a &str, returning the value and the number of bytes consumed.
+///
+/// The consumed bytes include any whitespace in front of the number. On failure, the reason
+/// is reported with the byte offset where parsing stopped. parse_float(), try_parse_float()
+/// and parse_float_with_rest() delegate to this one.
+pub fn parse_float_full<T: 'static + num::Float>(
+    s: &str,
+) -> Result<(T, usize), (ParseError, usize)> {
+    if s.chars().all(char::is_whitespace) {
+        return Err((ParseError::Empty, s.len()));
+    }
+
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let ret = parse_float_from_iter_internal::<T>(&mut chars, &ParseOptions::default());
+    let offset = byte_offset(s, chars.consumed());
+
+    ret.map(|ret| (ret, offset)).map_err(|err| (err, offset))
+}
+
+/// Number of bytes consumed by parse_float_trimmed().
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Consumed {
+    /// Bytes of the number, including any whitespace in front of it.
+    pub number_len: usize,
+    /// Bytes of the whitespace trimmed behind the number.
+    pub trailing_ws_len: usize,
+}
+
+/// Parse float values from a &str, trimming the whitespace behind the number.
+///
+/// Like parse_float_full(), but the whitespace behind the number is consumed as well, and
+/// reported separately, so the remainder can be taken in front of or behind it.
+pub fn parse_float_trimmed<T: 'static + num::Float>(
+    s: &str,
+) -> Result<(T, Consumed), (ParseError, usize)> {
+    let (ret, number_len) = parse_float_full(s)?;
+    let rest = &s[number_len..];
+
+    Ok((
+        ret,
+        Consumed {
+            number_len,
+            trailing_ws_len: rest.len() - rest.trim_start().len(),
+        },
+    ))
+}
+
+/// Parse float values from a &str, returning the remainder behind the number.
+///
+/// The longest valid number is taken, so parsing ends in front of anything that doesn't
+/// continue it: a second decimal point, like in `"12.34.56"`, as well as an exponent marker
+/// without digits, like in `"12e"` or `"12e-"`, are left in the remainder. A trailing decimal
+/// point is part of the number, so `"12."` leaves no remainder, unless it is doubled like the
+/// range operator in `"1..5"`, which leaves `"..5"`.
+pub fn parse_float_with_rest<T: 'static + num::Float>(s: &str) -> Option<(T, &str)> {
+    let (ret, len) = parse_float_full(s).ok()?;
+    Some((ret, &s[len..]))
+}
+
+/// Parse a float value followed by a word from a &str, like `"5 true"` or `"2.5 pi"`.
+///
+/// Whitespace between the number and the word is skipped. The word is the run of
+/// alphanumeric characters and underscores following the number, and must not be empty.
+pub fn parse_float_then_word<T: 'static + num::Float>(s: &str) -> Option<(T, &str)> {
+    let (ret, rest) = parse_float_with_rest::<T>(s)?;
+    let rest = rest.trim_start();
+    let end = rest
+        .find(|ch: char| !ch.is_alphanumeric() && ch != '_')
+        .unwrap_or(rest.len());
+
+    if end == 0 {
+        return None;
+    }
+
+    Some((ret, &rest[..end]))
+}
+
+/// Parse a float value or a named constant from an iterator, like `"2.5"` or `"pi"`.
+///
+/// When the input doesn't start with a number, the identifier of alphanumeric characters and
+/// underscores in front is passed to `lookup`. Spellings of infinity and NaN are parsed as
+/// numbers and never looked up. No sign is accepted in front of a constant.
+pub fn parse_float_or_const<T: 'static + num::Float>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    lookup: impl FnOnce(&str) -> Option<T>,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = Replay::new(chars);
+
+    while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    if chars
+        .peek()
+        .is_some_and(|ch| ch.is_alphabetic() || *ch == '_')
+    {
+        let mut name = Vec::new();
+
+        while let Some(ch) = chars
+            .peek()
+            .copied()
+            .filter(|ch| ch.is_alphanumeric() || *ch == '_')
+        {
+            name.push(ch);
+            chars.next();
+        }
+
+        let name = name.into_iter().collect::<String>();
+
+        if !options
+            .infinity
+            .iter()
+            .chain(&options.nan)
+            .any(|spelling| spelling.eq_ignore_ascii_case(&name))
+        {
+            return lookup(&name);
+        }
+
+        chars.unread(&name.chars().collect::<Vec<_>>());
+    }
+
+    parse_float_from_iter_internal(&mut chars, &options).ok()
+}
+
+/// Parse float values from a &str, telling whether the number was written as a float.
+///
+/// Plain integers are accepted as well, so the flag is set when the number had a decimal point
+/// or an exponent, or was infinity or NaN. This allows to keep int and float values apart.
+pub fn parse_float_typed<T: 'static + num::Float>(s: &str) -> Option<(T, bool)> {
+    let (ret, decimal) = scan_float(
+        &mut Replay::new(&mut s.chars().peekable()),
+        &ParseOptions::default(),
+    )
+    .ok()?;
+    Some((
+        ret,
+        decimal.is_none_or(|decimal| decimal.point || decimal.sci),
+    ))
+}
+
+/// Notation of a parsed float value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// The number was written without exponent, like `"1000"` or `"inf"`.
+    Plain,
+    /// The number was written with an exponent, like `"1e3"`.
+    Scientific,
+}
+
+/// Parse float values from an iterator with options, telling the notation of the number.
+///
+/// This allows to write the value back in the notation it was written in.
+pub fn parse_float_from_iter_with_notation<T: 'static + num::Float>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    options: &ParseOptions,
+) -> Option<(T, Notation)> {
+    let (ret, decimal) = scan_float(&mut Replay::new(chars), options).ok()?;
+
+    Some((
+        ret,
+        if decimal.is_some_and(|decimal| decimal.sci) {
+            Notation::Scientific
+        } else {
+            Notation::Plain
+        },
+    ))
+}
+
+/// Parse a comma-separated list of exactly `N` float values from a &str into an array.
+///
+/// Whitespace around the separators is accepted, but nothing else may follow the last value.
+pub fn parse_array<T: 'static + num::Float, const N: usize>(s: &str) -> Option<[T; N]> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let mut ret = [T::zero(); N];
+
+    for (i, item) in ret.iter_mut().enumerate() {
+        if i > 0 {
+            while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+                chars.next();
+            }
+
+            if chars.next() != Some(',') {
+                return None;
+            }
+        }
+
+        *item = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+    }
+
+    if chars.any(|ch| !ch.is_whitespace()) {
+        return None;
+    }
+
+    Some(ret)
+}
+
+/// Parse a sum of float values from a &str, like `"1.5+2.5-0.5"`.
+///
+/// The terms are separated by `+` and `-` operators, with whitespace accepted around them.
+/// An operator without a term behind it, a term with its own sign behind an operator, or
+/// anything else following the last term, makes the parse fail.
+pub fn parse_sum<T: 'static + num::Float>(s: &str) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let mut sum = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+
+    loop {
+        while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+            chars.next();
+        }
+
+        let neg = match chars.next() {
+            Some('+') => false,
+            Some('-') => true,
+            Some(_) => return None,
+            None => return Some(sum),
+        };
+
+        while chars.peek().is_some_and(|ch| ch.is_whitespace()) {
+            chars.next();
+        }
+
+        // The operator already gives the sign, so a term must not bring its own
+        if chars
+            .peek()
+            .is_some_and(|ch| *ch == '+' || *ch == options.neg_sign)
+        {
+            return None;
+        }
+
+        let term = parse_float_from_iter_internal::<T>(&mut chars, &options).ok()?;
+        sum = if neg { sum - term } else { sum + term };
+    }
+}
+
+/// Internal function to find the first float value in a &str, starting at byte offset `from`.
+///
+/// When `operators` is set, a sign directly behind an alphanumeric character or a `.` is not
+/// taken as part of the number. Returns the value and the byte offsets of its start and end.
+fn find_float_internal<T: 'static + num::Float>(
+    s: &str,
+    from: usize,
+    operators: bool,
+) -> Option<(T, usize, usize)> {
+    let is_digit = |rest: &str| {
+        let mut chars = rest.chars();
+
+        match chars.next() {
+            Some('.') => chars.next().is_some_and(|ch| ch.is_ascii_digit()),
+            Some(ch) => ch.is_ascii_digit(),
+            None => false,
+        }
+    };
+
+    let mut prev = s[..from].chars().next_back();
+
+    for (offset, ch) in s[from..].char_indices() {
+        let offset = from + offset;
+        let rest = &s[offset..];
+
+        let start = match ch {
+            '+' | '-' => {
+                is_digit(&rest[1..])
+                    && !(operators && prev.is_some_and(|ch| ch.is_alphanumeric() || ch == '.'))
+            }
+            _ => is_digit(rest),
+        };
+
+        if start {
+            let (ret, rest) = parse_float_with_rest::<T>(rest)?;
+            return Some((ret, offset, s.len() - rest.len()));
+        }
+
+        prev = Some(ch);
+    }
+
+    None
+}
+
+/// Find the first float value anywhere in a &str, like in `"price: $12.50 today"`.
+///
+/// Any characters in front of the number are skipped. A sign is only taken as part of the number
+/// when directly followed by digits, so `"a-b"` contains no number. Returns the value and its
+/// byte offset.
+pub fn find_first_float<T: 'static + num::Float>(s: &str) -> Option<(T, usize)> {
+    let (ret, offset, _) = find_float_internal(s, 0, false)?;
+    Some((ret, offset))
+}
+
+/// Find all float values in a &str, returning each value with its byte offset.
+///
+/// Works like find_first_float(), continuing behind each number found. When `operators` is set,
+/// a sign directly behind a number or a word is taken as an operator instead of a sign, so
+/// `"3-4"` yields 3 and 4, otherwise 3 and -4. A sign separated by whitespace, like in `"3 -4"`,
+/// is always taken as a sign.
+pub fn find_all_floats<T: 'static + num::Float>(s: &str, operators: bool) -> Vec<(T, usize)> {
+    let mut ret = Vec::new();
+    let mut from = 0;
+
+    while let Some((value, offset, end)) = find_float_internal(s, from, operators) {
+        ret.push((value, offset));
+        from = end;
+    }
+
+    ret
+}
+
+/// Parse all whitespace-separated float values from a &str into a Vec.
+///
+/// Tokens which aren't entirely a number are skipped, unless `strict` is set. In this case,
+/// the values parsed so far and the byte position of the offending token are returned as error.
+pub fn parse_float_vec<T: 'static + num::Float>(
+    s: &str,
+    strict: bool,
+) -> Result<Vec<T>, (Vec<T>, usize)> {
+    let mut ret = Vec::new();
+    let mut rest = s.trim_start();
+
+    while !rest.is_empty() {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        match parse_float_with_rest::<T>(&rest[..end]) {
+            Some((value, "")) => ret.push(value),
+            _ if strict => return Err((ret, s.len() - rest.len())),
+            _ => {}
+        }
+
+        rest = rest[end..].trim_start();
+    }
+
+    Ok(ret)
+}
+
+/// Rounding modes for converting float values into ints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero.
+    Trunc,
+    /// Round to the nearest int, and half-way cases away from zero.
+    Nearest,
+}
+
+/// Parse float values from a &str into an int, rounded by the given mode.
+///
+/// Unlike parse_int_sci(), non-integral values are accepted and rounded. Values outside the
+/// range of `T`, infinity and NaN are rejected. The digits are rounded as written, so the
+/// result is exact for any width of `T`.
+pub fn parse_float_as_int<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::CheckedSub + num::FromPrimitive,
+>(
+    s: &str,
+    mode: RoundingMode,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+
+    scan_decimal(&mut chars, prefix.neg, &options)?.to_int(Some(mode))
+}
+
+/// Parse a decimal number from a &str into its parts, without converting it.
+///
+/// The number is scanned like by parse_float(), but infinity and NaN are rejected.
+/// Digits are kept as written, including leading and trailing zeros.
+pub fn parse_decimal_parts(s: &str) -> Option<DecimalParts> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let decimal = scan_decimal(&mut chars, prefix.neg, &options)?;
+    let digits = |digits: &str| digits.bytes().map(|dig| dig - b'0').collect();
+
+    Some(DecimalParts {
+        negative: decimal.neg,
+        int_digits: digits(&decimal.int),
+        frac_digits: digits(&decimal.frac),
+        exp: decimal.exp,
+    })
+}
+
+/// Parse int values from a &str in scientific notation.
+///
+/// The number is scanned like a float, but must be integral, so `"1.5e3"` is accepted
+/// whereas `"1e-1"` is rejected. Values overflowing `T` are rejected as well.
+pub fn parse_int_sci<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::CheckedSub + num::FromPrimitive,
+>(
+    s: &str,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+
+    scan_decimal(&mut chars, prefix.neg, &options)?.to_int(None)
+}
+
+/// Internal function to convert a finite float into its exact value, as a rational number.
+fn float_to_ratio<T: num::Float>(value: T) -> num::BigRational {
+    let (mantissa, exp, sign) = value.integer_decode();
+    let mantissa = num::BigInt::from(mantissa) * sign;
+    let scale = num::pow(num::BigInt::from(2), exp.unsigned_abs() as usize);
+
+    if exp < 0 {
+        num::BigRational::new(mantissa, scale)
+    } else {
+        num::BigRational::from_integer(mantissa * scale)
+    }
+}
+
+/// Parse float values from a &str, rejecting them when their relative rounding error
+/// exceeds `tolerance`.
+///
+/// The error is computed against the exact value of the decimal number, which requires
+/// arbitrary precision arithmetic. As `0.1` has no exact binary representation,
+/// `parse_float_exact::<f64>("0.1", 0.0)` is rejected, whereas `"0.5"` is accepted.
+/// Values overflowing to infinity are always rejected, and values flushed to zero have a
+/// relative error of 1. Infinity and NaN are accepted when spelled out.
+pub fn parse_float_exact<T: 'static + num::Float>(s: &str, tolerance: T) -> Option<T> {
+    let (ret, decimal) = scan_float::<T>(
+        &mut Replay::new(&mut s.chars().peekable()),
+        &ParseOptions::default(),
+    )
+    .ok()?;
+
+    let Some(decimal) = decimal else {
+        return Some(ret);
+    };
+
+    if !ret.is_finite() || tolerance.is_nan() || tolerance < T::zero() {
+        return None;
+    }
+
+    // Avoid computing the exact value of tiny numbers like "1e-999999999"
+    if ret.is_zero() {
+        let zero = decimal
+            .int
+            .chars()
+            .chain(decimal.frac.chars())
+            .all(|ch| ch == '0');
+        return (zero || tolerance >= T::one()).then_some(ret);
+    }
+
+    let exact = decimal.to_ratio();
+    let error = num::Signed::abs(&(float_to_ratio(ret) - &exact));
+
+    (error <= float_to_ratio(tolerance) * num::Signed::abs(&exact)).then_some(ret)
+}
+
+/// Rules for writing numbers in normalized form, see parse_and_write_normalized().
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalization {
+    /// Grouping separator and group size written into the integer part, `None` for no grouping.
+    pub grouping: Option<(char, usize)>,
+    /// Write hex digits, radix prefixes and exponent markers in lowercase instead of uppercase.
+    pub lowercase: bool,
+    /// Write a `+` in front of positive exponents.
+    pub exponent_sign: bool,
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self {
+            grouping: None,
+            lowercase: true,
+            exponent_sign: false,
+        }
+    }
+}
+
+/// Internal representation of a number scanned for normalization.
+enum Normalized {
+    Special(f64),
+    Prefixed {
+        neg: bool,
+        prefix: char,
+        digits: String,
+    },
+    Decimal(Decimal),
+}
+
+/// Internal function to scan a number for normalization.
+fn scan_normalized(chars: &mut Replay, options: &ParseOptions) -> Result<Normalized, ParseError> {
+    let prefix = options.parse_prefix(chars, true);
+
+    let ret = if let Some(value) = scan_special::<f64>(chars, prefix.neg, options) {
+        Normalized::Special(value)
+    } else if chars.peek() == Some(&'0') {
+        chars.next();
+
+        match chars.peek().copied() {
+            Some(ch) if options.radix_prefix(ch).is_some() => {
+                chars.next();
+
+                let radix = options.radix_prefix(ch).unwrap();
+                let mut digits = String::new();
+
+                if options.scan_digits(chars, radix, |dig| digits.push(dig)) == 0 {
+                    return Err(ParseError::InvalidDigit);
+                }
+
+                Normalized::Prefixed {
+                    neg: prefix.neg,
+                    prefix: ch,
+                    digits,
+                }
+            }
+            _ => {
+                chars.unread(&['0']);
+                Normalized::Decimal(
+                    scan_decimal(chars, prefix.neg, options).ok_or(ParseError::InvalidDigit)?,
+                )
+            }
+        }
+    } else {
+        Normalized::Decimal(
+            scan_decimal(chars, prefix.neg, options).ok_or(ParseError::InvalidDigit)?,
+        )
+    };
+
+    if !options.parse_suffix(chars, &prefix) {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    Ok(ret)
+}
+
+/// Internal function to write digits without leading zeros, grouped and cased by the rules.
+fn write_digits(
+    out: &mut dyn std::fmt::Write,
+    digits: &str,
+    rules: &Normalization,
+) -> std::fmt::Result {
+    let digits = match digits.trim_start_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+
+    for (i, dig) in digits.chars().enumerate() {
+        if let Some((grouping, size)) = rules.grouping {
+            if i > 0 && size > 0 && (digits.len() - i) % size == 0 {
+                out.write_char(grouping)?;
+            }
+        }
+
+        out.write_char(if rules.lowercase {
+            dig.to_ascii_lowercase()
+        } else {
+            dig.to_ascii_uppercase()
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Internal function to write a number scanned for normalization.
+fn write_normalized(
+    out: &mut dyn std::fmt::Write,
+    number: Normalized,
+    rules: &Normalization,
+) -> std::fmt::Result {
+    match number {
+        Normalized::Special(value) if value.is_nan() => out.write_str("NaN"),
+        Normalized::Special(value) => out.write_str(if value < 0.0 { "-inf" } else { "inf" }),
+        Normalized::Prefixed {
+            neg,
+            prefix,
+            digits,
+        } => {
+            out.write_str(if neg { "-0" } else { "0" })?;
+            out.write_char(if rules.lowercase {
+                prefix.to_ascii_lowercase()
+            } else {
+                prefix.to_ascii_uppercase()
+            })?;
+            write_digits(out, &digits, rules)
+        }
+        Normalized::Decimal(decimal) => {
+            if decimal.neg {
+                out.write_char('-')?;
+            }
+
+            write_digits(out, &decimal.int, rules)?;
+
+            let frac = decimal.frac.trim_end_matches('0');
+
+            if !frac.is_empty() {
+                out.write_char('.')?;
+                out.write_str(frac)?;
+            }
+
+            if decimal.sci {
+                out.write_char(if rules.lowercase { 'e' } else { 'E' })?;
+
+                if rules.exponent_sign && decimal.exp >= 0 {
+                    out.write_char('+')?;
+                }
+
+                write!(out, "{}", decimal.exp)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Parse a number from a &str and write it in normalized form to `out`.
+///
+/// The number is recognized as configured by `options`, and written as configured by `rules`,
+/// without whitespace, positive sign and leading zeros. Fractions are written without trailing
+/// zeros, and an exponent is only written when the number had one. Radix-prefixed ints are
+/// written in their radix, like `"0XFF"` as `"0xff"`.
+///
+/// Returns the number of bytes of `src` taken by the number. The number is scanned before
+/// anything is written, so on invalid input, None is returned and nothing is written.
+/// None is also returned when writing fails.
+pub fn parse_and_write_normalized(
+    src: &str,
+    out: &mut dyn std::fmt::Write,
+    options: &ParseOptions,
+    rules: &Normalization,
+) -> Option<usize> {
+    let (number, rest) = parse_with_rest(src, |chars| scan_normalized(chars, options)).ok()?;
+
+    write_normalized(out, number, rules).ok()?;
+    Some(src.len() - rest.len())
+}
+
+/// Internal writer comparing the output against a source, only allocating once they differ.
+struct CowWriter<'a> {
+    src: &'a str,
+    pos: usize,
+    owned: Option<String>,
+}
+
+impl std::fmt::Write for CowWriter<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        match &mut self.owned {
+            Some(owned) => owned.push_str(s),
+            None if self.src[self.pos..].starts_with(s) => self.pos += s.len(),
+            None => {
+                let mut owned = self.src[..self.pos].to_string();
+                owned.push_str(s);
+                self.owned = Some(owned);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Normalize a number from a &str, which must contain the number only, as done by
+/// parse_and_write_normalized().
+///
+/// Returns the input borrowed when it is already normalized, so only inputs actually changed
+/// by the normalization are allocated. Whitespace around the number is accepted, but removed.
+pub fn normalize_number<'a>(
+    s: &'a str,
+    options: &ParseOptions,
+    rules: &Normalization,
+) -> Option<std::borrow::Cow<'a, str>> {
+    let mut out = CowWriter {
+        src: s,
+        pos: 0,
+        owned: None,
+    };
+    let len = parse_and_write_normalized(s, &mut out, options, rules)?;
+
+    if !s[len..].chars().all(char::is_whitespace) {
+        return None;
+    }
+
+    Some(match out.owned {
+        Some(owned) => std::borrow::Cow::Owned(owned),
+        None => std::borrow::Cow::Borrowed(&s[..out.pos]),
+    })
+}
+
+#[test]
+fn test_parse_float_f64() {
+    assert_eq!(parse_float::<f64>("1.25"), Some(1.25));
+    assert_eq!(parse_float::<f64>("  -1.25hello"), Some(-1.25));
+    assert_eq!(parse_float::<f64>("+1.5e3"), Some(1500.0));
+    assert_eq!(parse_float::<f64>("1.5E-3"), Some(0.0015));
+    assert_eq!(parse_float::<f64>("-1337.0e-30f32"), Some(-1337.0e-30));
+    assert_eq!(parse_float::<f64>(".5"), Some(0.5));
+    assert_eq!(parse_float::<f64>("5."), Some(5.0));
+    assert_eq!(parse_float::<f64>("42"), Some(42.0));
+    assert_eq!(parse_float::<f64>("1e"), Some(1.0));
+    assert_eq!(parse_float::<f64>("1e+"), Some(1.0));
+    assert_eq!(parse_float::<f64>("."), None);
+    assert_eq!(parse_float::<f64>("e5"), None);
+    assert_eq!(parse_float::<f64>("- 1"), None);
+    assert_eq!(parse_float::<f64>(""), None);
+}
+
+#[test]
+fn test_parse_float_precision() {
+    // Halfway between 1.0 and the next f64, rounding to even
+    assert_eq!(
+        parse_float::<f64>("1.00000000000000011102230246251565404236316680908203125"),
+        Some(1.0)
+    );
+    // Slightly above halfway, rounding up
+    assert_eq!(
+        parse_float::<f64>("1.00000000000000011102230246251565404236316680908203126"),
+        Some(1.0000000000000002)
+    );
+    assert_eq!(
+        parse_float::<f64>(&format!("{:.0}", f64::MAX)),
+        Some(f64::MAX)
+    );
+    assert_eq!(parse_float::<f32>("16777217"), Some(16777216.0f32));
+    assert_eq!(
+        parse_float::<f32>("0.000000000000000000000000000000000000011754943508222875"),
+        Some(f32::MIN_POSITIVE)
+    );
+}
+
+#[test]
+fn test_parse_float_leading_point_exponent() {
+    assert_eq!(parse_float::<f64>(".5e3"), Some(500.0));
+    assert_eq!(parse_float::<f64>(".5e-3"), Some(0.0005));
+    assert_eq!(parse_float::<f64>("-.5E+3"), Some(-500.0));
+    assert_eq!(parse_float::<f32>(".5e-3"), Some(0.0005f32));
+    assert_eq!(parse_float::<f64>(".e3"), None);
+}
+
+#[test]
+fn test_parse_float_signs() {
+    // The mantissa's and the exponent's sign are independent
+    assert_eq!(parse_float::<f64>("1.5e3"), Some(1500.0));
+    assert_eq!(parse_float::<f64>("1.5e-3"), Some(0.0015));
+    assert_eq!(parse_float::<f64>("-1.5e3"), Some(-1500.0));
+    assert_eq!(parse_float::<f64>("-1.5e-3"), Some(-0.0015));
+    assert_eq!(parse_float::<f64>("+1.5e+3"), Some(1500.0));
+    assert_eq!(parse_float::<f64>("-1.5e+3"), Some(-1500.0));
+}
+
+#[test]
+fn test_parse_float_signed_zero() {
+    for (input, negative) in [
+        ("0e5", false),
+        ("-0e5", true),
+        ("-0.0e-10", true),
+        ("+0.000E+99", false),
+        ("-.0e1", true),
+        ("-0e999999999", true),
+        ("-0e-999999999", true),
+        ("-1e-999", true),
+    ] {
+        let ret = parse_float::<f64>(input).unwrap();
+        assert_eq!(ret, 0.0, "{}", input);
+        assert_eq!(ret.is_sign_negative(), negative, "{}", input);
+
+        let ret = parse_float::<f32>(input).unwrap();
+        assert_eq!(ret.is_sign_negative(), negative, "{}", input);
+    }
+
+    let options = ParseOptions {
+        exponent_base: 2,
+        ..Default::default()
+    };
+    let ret = parse_float_from_iter_with_options::<f64>(&mut "-0e5".chars().peekable(), &options);
+    assert!(ret.is_some_and(|ret| ret == 0.0 && ret.is_sign_negative()));
+}
+
+#[test]
+fn test_parse_float_f32() {
+    assert_eq!(parse_float::<f32>("1.25"), Some(1.25f32));
+    assert_eq!(parse_float::<f32>("-1337.0e-30f32"), Some(-1337.0e-30f32));
+    assert_eq!(parse_float::<f32>("1e39"), Some(f32::INFINITY));
+}
+
+#[test]
+fn test_parse_float_exact() {
+    assert_eq!(parse_float_exact::<f64>("0.1", 0.0), None);
+    assert_eq!(parse_float_exact::<f64>("0.1", 1e-16), Some(0.1));
+    assert_eq!(parse_float_exact::<f64>("0.1", 1e-17), None);
+    assert_eq!(parse_float_exact::<f64>("0.5", 0.0), Some(0.5));
+    assert_eq!(parse_float_exact::<f64>("-1.25e3", 0.0), Some(-1250.0));
+    assert_eq!(parse_float_exact::<f64>("9007199254740993", 0.0), None);
+    assert_eq!(
+        parse_float_exact::<f64>("9007199254740993", 1e-15),
+        Some(9007199254740992.0)
+    );
+    assert_eq!(parse_float_exact::<f32>("0.1", 2e-8), Some(0.1));
+    assert_eq!(parse_float_exact::<f32>("0.1", 1e-8), None);
+    assert_eq!(parse_float_exact::<f64>("0e-999999999", 0.0), Some(0.0));
+    assert_eq!(parse_float_exact::<f64>("1e-999999999", 0.5), None);
+    assert_eq!(parse_float_exact::<f64>("1e-999999999", 1.0), Some(0.0));
+    assert_eq!(parse_float_exact::<f64>("1e999", 1.0), None);
+    assert_eq!(parse_float_exact::<f64>("inf", 0.0), Some(f64::INFINITY));
+    assert_eq!(parse_float_exact::<f64>("0.5", -1.0), None);
+    assert_eq!(parse_float_exact::<f64>("x", 1.0), None);
+}
+
+#[test]
+fn test_parse_and_write_normalized() {
+    let normalize = |s: &str, options: &ParseOptions, rules: &Normalization| {
+        let mut out = String::new();
+        parse_and_write_normalized(s, &mut out, options, rules).map(|len| (out, len))
+    };
+    let options = ParseOptions {
+        grouping: Some(','),
+        ..Default::default()
+    };
+    let rules = Normalization::default();
+
+    for (input, expected) in [
+        (" +1,234.500", Some(("1234.5", 11))),
+        ("-0001.0", Some(("-1", 7))),
+        ("0.0", Some(("0", 3))),
+        (".5", Some(("0.5", 2))),
+        ("1.50E+03", Some(("1.5e3", 8))),
+        ("1e-05", Some(("1e-5", 5))),
+        ("1ex", Some(("1", 1))),
+        ("0XFF,FF", Some(("0xffff", 7))),
+        ("-0x0", Some(("-0x0", 4))),
+        ("-Infinity", Some(("-inf", 9))),
+        ("nan", Some(("NaN", 3))),
+        ("12 apples", Some(("12", 2))),
+        ("0x", None),
+        ("x", None),
+    ] {
+        assert_eq!(
+            normalize(input, &options, &rules),
+            expected.map(|(out, len)| (out.to_string(), len)),
+            "{}",
+            input
+        );
+    }
+
+    let rules = Normalization {
+        grouping: Some(('_', 3)),
+        lowercase: false,
+        exponent_sign: true,
+    };
+
+    assert_eq!(
+        normalize("1234567.25e2", &options, &rules),
+        Some(("1_234_567.25E+2".to_string(), 12))
+    );
+    assert_eq!(
+        normalize("0xabcdef", &options, &rules),
+        Some(("0XABC_DEF".to_string(), 8))
+    );
+    assert_eq!(
+        normalize("123", &options, &rules),
+        Some(("123".to_string(), 3))
+    );
+
+    let mut out = String::new();
+    assert_eq!(
+        parse_and_write_normalized("-", &mut out, &options, &rules),
+        None
+    );
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_normalize_number() {
+    use std::borrow::Cow;
+
+    let options = ParseOptions {
+        grouping: Some(','),
+        ..Default::default()
+    };
+    let rules = Normalization::default();
+
+    for (input, expected) in [
+        ("1234.5", Some(Cow::Borrowed("1234.5"))),
+        ("-1.5e3", Some(Cow::Borrowed("-1.5e3"))),
+        ("0xff", Some(Cow::Borrowed("0xff"))),
+        ("1234.5 ", Some(Cow::Borrowed("1234.5"))),
+        ("1,234.5", Some(Cow::Owned("1234.5".to_string()))),
+        ("0XFF", Some(Cow::Owned("0xff".to_string()))),
+        ("+1.50", Some(Cow::Owned("1.5".to_string()))),
+        (" 1", Some(Cow::Owned("1".to_string()))),
+        ("1E3", Some(Cow::Owned("1e3".to_string()))),
+        ("1.5x", None),
+        ("", None),
+    ] {
+        let ret = normalize_number(input, &options, &rules);
+        assert_eq!(ret, expected, "{}", input);
+        assert_eq!(
+            ret.is_some_and(|ret| matches!(ret, Cow::Borrowed(_))),
+            expected.is_some_and(|expected| matches!(expected, Cow::Borrowed(_))),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_float_f32_subnormal() {
+    for input in [
+        "1e-40",
+        "-1e-40",
+        "1.17549435e-38",
+        "1.1754942e-38",
+        "1.401298464324817e-45",
+        "1e-45",
+        "7.1e-46",
+        "7e-46",
+        "1e-46",
+        "-1e-46",
+        "-1337.0e-326",
+        "0.000000000000000000000000000000000000000000001",
+    ] {
+        assert_eq!(
+            parse_float::<f32>(input).map(f32::to_bits),
+            input.parse::<f32>().ok().map(f32::to_bits),
+            "{}",
+            input
+        );
+    }
+
+    assert_eq!(
+        parse_float::<f32>("1.401298464324817e-45"),
+        Some(f32::from_bits(1))
+    );
+    assert_eq!(parse_float::<f32>("1e-46"), Some(0.0));
+}
+
+#[test]
+fn test_parse_float_terminators() {
+    let options = ParseOptions {
+        terminators: Some(vec![';', ',']),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "12.5;".chars().peekable(), &options),
+        Some(12.5)
+    );
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "12.5".chars().peekable(), &options),
+        Some(12.5)
+    );
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "12.5px".chars().peekable(), &options),
+        None
+    );
+}
+
+#[test]
+fn test_parse_float_special() {
+    assert_eq!(parse_float::<f64>("inf"), Some(f64::INFINITY));
+    assert_eq!(parse_float::<f64>(" +Inf"), Some(f64::INFINITY));
+    assert_eq!(parse_float::<f64>("-infinity"), Some(f64::NEG_INFINITY));
+    assert_eq!(parse_float::<f64>("-INFINITE"), Some(f64::NEG_INFINITY));
+    assert_eq!(parse_float::<f64>("in"), None);
+    assert!(parse_float::<f64>("NaN").unwrap().is_nan());
+
+    let options = ParseOptions {
+        infinity: vec!["inf", "∞", "1.#INF"],
+        nan: vec![],
+        ..Default::default()
+    };
+
+    for (input, expected) in [
+        ("∞", Some(f64::INFINITY)),
+        ("-∞", Some(f64::NEG_INFINITY)),
+        ("1.#INF", Some(f64::INFINITY)),
+        ("-1.#inf", Some(f64::NEG_INFINITY)),
+        ("1.5", Some(1.5)),
+        ("1.#", Some(1.0)),
+        ("infinity", Some(f64::INFINITY)),
+        ("nan", None),
+    ] {
+        assert_eq!(
+            parse_float_from_iter_with_options::<f64>(&mut input.chars().peekable(), &options),
+            expected,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_float_finite_only() {
+    let options = ParseOptions {
+        finite_only: true,
+        ..Default::default()
+    };
+    let parse = |s: &str| {
+        parse_float_from_iter_internal::<f64>(&mut Replay::new(&mut s.chars().peekable()), &options)
+    };
+
+    assert_eq!(parse("1.5"), Ok(1.5));
+    assert_eq!(parse("1e308"), Ok(1e308));
+    assert_eq!(parse("inf"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("-inf"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("NaN"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("1e999"), Err(ParseError::Overflow { positive: true }));
+    assert_eq!(
+        parse("-1e999"),
+        Err(ParseError::Overflow { positive: false })
+    );
+    assert_eq!(
+        parse_float_from_iter_internal::<f32>(
+            &mut Replay::new(&mut "1e39".chars().peekable()),
+            &options
+        ),
+        Err(ParseError::Overflow { positive: true })
+    );
+    assert_eq!(parse_float::<f32>("1e39"), Some(f32::INFINITY));
+}
+
+#[test]
+fn test_parse_float_sign_markers() {
+    let options = ParseOptions {
+        sign_markers: Some(("CR", "DB")),
+        ..Default::default()
+    };
+    let parse = |s: &str| {
+        let mut chars = s.chars().peekable();
+        let mut chars = Replay::new(&mut chars);
+        let ret = parse_float_from_iter_internal::<f64>(&mut chars, &options).ok();
+        (ret, chars.collect::<String>())
+    };
+
+    assert_eq!(parse("100.00CR"), (Some(-100.0), "".to_string()));
+    assert_eq!(parse("100.00DB"), (Some(100.0), "".to_string()));
+    assert_eq!(parse("100.00 cr;"), (Some(-100.0), ";".to_string()));
+    assert_eq!(parse("100.00"), (Some(100.0), "".to_string()));
+    assert_eq!(parse("100.00 CREDIT"), (Some(100.0), " CREDIT".to_string()));
+    assert_eq!(parse("-100.00CR").0, None);
+    assert_eq!(parse("+100.00DB").0, None);
+    assert_eq!(parse("-100.00").0, Some(-100.0));
+    assert_eq!(parse("1e999CR").0, Some(f64::NEG_INFINITY));
+    assert_eq!(parse_float::<f64>("100.00CR"), Some(100.0));
+
+    let options = ParseOptions {
+        sign_markers: Some(("DB", "CR")),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "100.00DB".chars().peekable(), &options),
+        Some(-100.0)
+    );
+}
+
+#[test]
+fn test_try_parse_float() {
+    assert_eq!(try_parse_float::<f64>(" 2.5 "), Ok(2.5));
+    assert_eq!(try_parse_float::<f64>(""), Err(ParseError::Empty));
+    assert_eq!(try_parse_float::<f64>("   "), Err(ParseError::Empty));
+    assert_eq!(try_parse_float::<f64>("abc"), Err(ParseError::InvalidDigit));
+    assert_eq!(try_parse_float::<f64>("-."), Err(ParseError::InvalidDigit));
+}
+
+#[test]
+fn test_parse_float_typed() {
+    assert_eq!(parse_float_typed::<f64>("42"), Some((42.0, false)));
+    assert_eq!(parse_float_typed::<f64>(" -42 "), Some((-42.0, false)));
+    assert_eq!(parse_float_typed::<f64>("42.0"), Some((42.0, true)));
+    assert_eq!(parse_float_typed::<f64>("42."), Some((42.0, true)));
+    assert_eq!(parse_float_typed::<f64>(".5"), Some((0.5, true)));
+    assert_eq!(parse_float_typed::<f64>("4e2"), Some((400.0, true)));
+    assert_eq!(parse_float_typed::<f64>("4e"), Some((4.0, false)));
+    assert_eq!(parse_float_typed::<f64>("inf"), Some((f64::INFINITY, true)));
+    assert_eq!(parse_float_typed::<f64>("x"), None);
+}
+
+#[test]
+fn test_parse_float_with_notation() {
+    let parse = |s: &str| {
+        parse_float_from_iter_with_notation::<f64>(
+            &mut s.chars().peekable(),
+            &ParseOptions::default(),
+        )
+    };
+
+    assert_eq!(parse("1e3"), Some((1000.0, Notation::Scientific)));
+    assert_eq!(parse("-1.5E-3"), Some((-0.0015, Notation::Scientific)));
+    assert_eq!(parse("1000"), Some((1000.0, Notation::Plain)));
+    assert_eq!(parse("1.5"), Some((1.5, Notation::Plain)));
+    assert_eq!(parse("1e"), Some((1.0, Notation::Plain)));
+    assert_eq!(parse("inf"), Some((f64::INFINITY, Notation::Plain)));
+    assert_eq!(parse("e3"), None);
+}
+
+#[test]
+fn test_parse_array() {
+    assert_eq!(
+        parse_array::<f64, 3>("1.0, 2.0, 3.0"),
+        Some([1.0, 2.0, 3.0])
+    );
+    assert_eq!(
+        parse_array::<f64, 3>(" 1,2 ,-3e1 "),
+        Some([1.0, 2.0, -30.0])
+    );
+    assert_eq!(parse_array::<f32, 2>("1.5,2.5"), Some([1.5f32, 2.5f32]));
+    assert_eq!(parse_array::<f64, 3>("1.0, 2.0"), None);
+    assert_eq!(parse_array::<f64, 3>("1.0, 2.0, 3.0, 4.0"), None);
+    assert_eq!(parse_array::<f64, 3>("1.0, 2.0, 3.0x"), None);
+    assert_eq!(parse_array::<f64, 3>("1e,2,3"), None);
+    assert_eq!(parse_array::<f64, 2>("1, 2e+"), None);
+    assert_eq!(parse_array::<f64, 3>("1.0, , 3.0"), None);
+    assert_eq!(parse_array::<f64, 2>("1.0; 2.0"), None);
+    assert_eq!(parse_array::<f64, 0>(" "), Some([]));
+}
+
+#[test]
+fn test_parse_sum() {
+    assert_eq!(parse_sum::<f64>("1.5+2.5-0.5"), Some(3.5));
+    assert_eq!(parse_sum::<f64>(" -1 - 2 + 0.5 "), Some(-2.5));
+    assert_eq!(parse_sum::<f64>("1e2+1"), Some(101.0));
+    assert_eq!(parse_sum::<f64>("42"), Some(42.0));
+    assert_eq!(parse_sum::<f64>("1+"), None);
+    assert_eq!(parse_sum::<f64>("1-2-"), None);
+    assert_eq!(parse_sum::<f64>("1e+"), None);
+    assert_eq!(parse_sum::<f64>("2*3"), None);
+    assert_eq!(parse_sum::<f64>("1++2"), None);
+    assert_eq!(parse_sum::<f64>("1+-2"), None);
+    assert_eq!(parse_sum::<f64>("1 - -2"), None);
+    assert_eq!(parse_sum::<f64>("1 + +2"), None);
+    assert_eq!(parse_sum::<f64>(""), None);
+}
+
+#[test]
+fn test_parse_float_grouping() {
+    let options = ParseOptions {
+        grouping: Some('\''),
+        ..Default::default()
+    };
+
+    let parse_f64 =
+        |s: &str| parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &options);
+
+    assert_eq!(parse_f64("1'234'567.5"), Some(1234567.5));
+    assert_eq!(parse_f64("-1'234e3"), Some(-1234000.0));
+    assert_eq!(parse_f64("'1"), None);
+}
+
+#[test]
+fn test_parse_float_grouping_fraction() {
+    let options = ParseOptions {
+        grouping: Some(','),
+        ..Default::default()
+    };
+
+    let mut chars = "1,234.5,6".chars().peekable();
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut chars, &options),
+        Some(1234.5)
+    );
+    assert_eq!(chars.collect::<String>(), ",6");
+
+    let options = ParseOptions {
+        grouping: Some(','),
+        terminators: Some(vec![';']),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "1.23,4".chars().peekable(), &options),
+        None
+    );
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "1,23;4".chars().peekable(), &options),
+        Some(123.0)
+    );
+}
+
+#[test]
+fn test_readme_float() {
+    assert_eq!(parse_float::<f64>(" -1.5e3 as f64 "), Some(-1500.0f64));
+    assert_eq!(parse_float::<f32>(".5 as f32 "), Some(0.5f32));
+    assert_eq!(parse_float::<f64>(" . is invalid "), None);
+}
+
+#[test]
+fn test_parse_int_sci() {
+    assert_eq!(parse_int_sci::<i64>("1e3"), Some(1000));
+    assert_eq!(parse_int_sci::<i64>("1.5e3"), Some(1500));
+    assert_eq!(parse_int_sci::<i64>("-1.5e3"), Some(-1500));
+    assert_eq!(parse_int_sci::<i64>("1500e-2"), Some(15));
+    assert_eq!(parse_int_sci::<i64>("1.000"), Some(1));
+    assert_eq!(parse_int_sci::<i64>("0e99"), Some(0));
+    assert_eq!(parse_int_sci::<i64>("1e-1"), None);
+    assert_eq!(parse_int_sci::<i64>("1.5"), None);
+    assert_eq!(parse_int_sci::<i8>("1e3"), None);
+    assert_eq!(parse_int_sci::<u32>("-1e3"), None);
+    assert_eq!(parse_int_sci::<u32>("-0e3"), Some(0));
+    assert_eq!(parse_int_sci::<i8>("-128"), Some(-128));
+    assert_eq!(parse_int_sci::<i8>("-1.28e2"), Some(-128));
+    assert_eq!(parse_int_sci::<i8>("-129"), None);
+    assert_eq!(parse_int_sci::<i8>("128"), None);
+    assert_eq!(parse_int_sci::<u32>("abc"), None);
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_parse_float_internal_whitespace() {
+    assert_eq!(parse_float::<f64>("3.14 15"), Some(3.14));
+    assert_eq!(parse_float_with_rest::<f64>("3.14 15"), Some((3.14, " 15")));
+    assert_eq!(parse_float_with_rest::<f64>("3. 14"), Some((3.0, " 14")));
+    assert_eq!(parse_float_with_rest::<f64>("1 000"), Some((1.0, " 000")));
+    assert_eq!(parse_float_with_rest::<f64>("1e 5"), Some((1.0, "e 5")));
+    assert_eq!(parse_float_with_rest::<f64>("1.5e5 0"), Some((1.5e5, " 0")));
+
+    // Only space grouping accepts spaces, and only between integer digits
+    let options = ParseOptions {
+        space_grouping: true,
+        ..Default::default()
+    };
+    let mut chars = "1 000.5 5".chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+
+    assert_eq!(
+        parse_float_from_iter_internal::<f64>(&mut chars, &options),
+        Ok(1000.5)
+    );
+    assert_eq!(chars.collect::<String>(), " 5");
+}
+
+#[test]
+fn test_parse_float_then_word() {
+    assert_eq!(parse_float_with_rest::<f64>(" 2.5 pi"), Some((2.5, " pi")));
+    assert_eq!(parse_float_with_rest::<f64>("1e+x"), Some((1.0, "e+x")));
+    assert_eq!(
+        parse_float_with_rest::<f64>("-inf"),
+        Some((f64::NEG_INFINITY, ""))
+    );
+
+    assert_eq!(parse_float_then_word::<f64>("5 true"), Some((5.0, "true")));
+    assert_eq!(
+        parse_float_then_word::<f64>("2.5 pi rest"),
+        Some((2.5, "pi"))
+    );
+    assert_eq!(
+        parse_float_then_word::<f64>("2.5\tmax_value+1"),
+        Some((2.5, "max_value"))
+    );
+    assert_eq!(
+        parse_float_then_word::<f64>("1.5e3km"),
+        Some((1500.0, "km"))
+    );
+    assert_eq!(parse_float_then_word::<f64>("1ex"), Some((1.0, "ex")));
+    assert_eq!(parse_float_then_word::<f64>("5"), None);
+    assert_eq!(parse_float_then_word::<f64>("5 +"), None);
+    assert_eq!(parse_float_then_word::<f64>("pi"), None);
+
+    // A dangling exponent stays in the caller's iterator
+    for (s, rest) in [("12ex", "ex"), ("12e-x", "e-x"), ("12E+", "E+")] {
+        let mut chars = Pushback::new(s.chars());
+        assert_eq!(parse_float_from_iter::<f64>(&mut chars, true), Some(12.0));
+        assert_eq!(chars.collect::<String>(), rest);
+    }
+}
+
+#[test]
+fn test_parse_float_or_const() {
+    let consts = std::collections::HashMap::from([("pi", 3.5), ("tau_2", 7.0)]);
+    let parse = |s: &str| {
+        parse_float_or_const::<f64>(&mut s.chars().peekable(), |name| consts.get(name).copied())
+    };
+
+    assert_eq!(parse("2.5"), Some(2.5));
+    assert_eq!(parse(" -2.5*pi"), Some(-2.5));
+    assert_eq!(parse("pi"), Some(3.5));
+    assert_eq!(parse(" tau_2 "), Some(7.0));
+    assert_eq!(parse("Infinity"), Some(f64::INFINITY));
+    assert!(parse("nan").is_some_and(f64::is_nan));
+    assert_eq!(parse("e"), None);
+    assert_eq!(parse("-pi"), None);
+    assert_eq!(parse("*"), None);
+
+    let mut chars = "pi*2".chars().peekable();
+    assert_eq!(parse_float_or_const(&mut chars, |_| Some(1.0)), Some(1.0));
+    assert_eq!(chars.next(), Some('*'));
+}
+
+#[test]
+fn test_parse_float_vec() {
+    assert_eq!(
+        parse_float_vec::<f64>(" 1.0 2.0e3\n-4.5\t6 ", true),
+        Ok(vec![1.0, 2000.0, -4.5, 6.0])
+    );
+    assert_eq!(parse_float_vec::<f64>("", true), Ok(vec![]));
+    assert_eq!(
+        parse_float_vec::<f64>("1 x 2.5 3y", false),
+        Ok(vec![1.0, 2.5])
+    );
+    assert_eq!(
+        parse_float_vec::<f64>("1 2.5 3y 4", true),
+        Err((vec![1.0, 2.5], 6))
+    );
+}
+
+#[test]
+fn test_parse_float_exponent_sign() {
+    let parse = |s: &str, exponent_sign| {
+        parse_float_from_iter_with_options::<f64>(
+            &mut s.chars().peekable(),
+            &ParseOptions {
+                exponent_sign,
+                ..Default::default()
+            },
+        )
+    };
+
+    for (s, optional, required, forbidden) in [
+        ("1e5", Some(1e5), None, Some(1e5)),
+        ("1e+5", Some(1e5), Some(1e5), None),
+        ("1e-5", Some(1e-5), Some(1e-5), None),
+        ("-1.5E-5", Some(-1.5e-5), Some(-1.5e-5), None),
+        ("15", Some(15.0), Some(15.0), Some(15.0)),
+        ("1e+", Some(1.0), Some(1.0), Some(1.0)),
+        ("1e", Some(1.0), Some(1.0), Some(1.0)),
+    ] {
+        assert_eq!(parse(s, ExponentSign::Optional), optional, "{s}");
+        assert_eq!(parse(s, ExponentSign::Required), required, "{s}");
+        assert_eq!(parse(s, ExponentSign::Forbidden), forbidden, "{s}");
+    }
+}
+
+#[test]
+fn test_parse_float_max_exponent() {
+    let options = ParseOptions {
+        max_exponent: Some(308),
+        ..Default::default()
+    };
+    let parse = |s: &str| {
+        parse_float_from_iter_internal::<f64>(&mut Replay::new(&mut s.chars().peekable()), &options)
+    };
+
+    assert_eq!(parse_float::<f64>("1e999"), Some(f64::INFINITY));
+    assert_eq!(parse("1e999"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("1e-999"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("1e308"), Ok(1e308));
+    assert_eq!(parse("-2.5E+309"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("1e99999999999"), Err(ParseError::InvalidDigit));
+    assert_eq!(parse("1e"), Ok(1.0));
+    assert_eq!(parse("1000"), Ok(1000.0));
+}
+
+#[test]
+fn test_parse_float_range() {
+    assert_eq!(parse_float_with_rest::<f64>("1..5"), Some((1.0, "..5")));
+    assert_eq!(parse_float_with_rest::<f64>("1...5"), Some((1.0, "...5")));
+    assert_eq!(parse_float_with_rest::<f64>("1.5..2"), Some((1.5, "..2")));
+    assert_eq!(
+        parse_float_with_rest::<f64>("1.e3..2"),
+        Some((1000.0, "..2"))
+    );
+    assert_eq!(parse_float_with_rest::<f64>("1. .5"), Some((1.0, " .5")));
+    assert_eq!(parse_float_with_rest::<f64>("..5"), None);
+    assert_eq!(parse_float_typed::<f64>("1..5"), Some((1.0, false)));
+
+    let options = ParseOptions {
+        allow_trailing_dot: false,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "1..5".chars().peekable(), &options),
+        Some(1.0)
+    );
+
+    // The doubled decimal point stays in the caller's iterator
+    for (s, expected, rest) in [
+        ("1..5", 1.0, "..5"),
+        ("1.5...", 1.5, "..."),
+        ("2..", 2.0, ".."),
+    ] {
+        let mut chars = Pushback::new(s.chars());
+        assert_eq!(
+            parse_float_from_iter::<f64>(&mut chars, true),
+            Some(expected)
+        );
+        assert_eq!(chars.collect::<String>(), rest, "{s}");
+    }
+}
+
+#[test]
+fn test_parse_float_trailing_dot() {
+    let options = ParseOptions {
+        allow_trailing_dot: false,
+        ..Default::default()
+    };
+
+    let parse_f64 =
+        |s: &str| parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &options);
+
+    assert_eq!(parse_float::<f64>("5."), Some(5.0));
+    assert_eq!(parse_float::<f64>("5.e1"), Some(50.0));
+    assert_eq!(parse_f64("5."), None);
+    assert_eq!(parse_f64("5.e1"), None);
+    assert_eq!(parse_f64("5.0"), Some(5.0));
+    assert_eq!(parse_f64(".5"), Some(0.5));
+    assert_eq!(parse_f64("5"), Some(5.0));
+}
+
+#[test]
+fn test_parse_float_from_chunks() {
+    assert_eq!(parse_float_from_chunks::<f64>(["1.", "25"]), Some(1.25));
+    assert_eq!(
+        parse_float_from_chunks::<f64>([" -", "", "1", "e", "3 x"]),
+        Some(-1000.0)
+    );
+    assert_eq!(
+        parse_float_from_chunks::<f64>(["in", "f"]),
+        Some(f64::INFINITY)
+    );
+    assert_eq!(parse_float_from_chunks::<f64>(["", " "]), None);
+    assert_eq!(
+        parse_float_from_chunks::<f64>("1.5|e2".split('|')),
+        Some(150.0)
+    );
+}
+
+#[test]
+fn test_parse_float_nan_payload() {
+    let (nan, rest) = parse_float_with_rest::<f64>("NaN(1)").unwrap();
+    assert!(nan.is_nan());
+    assert_eq!(rest, "(1)");
+
+    let (nan, rest) = parse_float_with_rest::<f64>("-nan(0x1234)").unwrap();
+    assert!(nan.is_nan());
+    assert_eq!(rest, "(0x1234)");
+
+    assert_eq!(try_parse_float::<f64>("NaN(1)").map(f64::is_nan), Ok(true));
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(
+            &mut "NaN(1)".chars().peekable(),
+            &ParseOptions {
+                terminators: Some(vec![]),
+                ..Default::default()
+            }
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_find_first_float() {
+    assert_eq!(
+        find_first_float::<f64>("price: $12.50 today"),
+        Some((12.5, 8))
+    );
+    assert_eq!(find_first_float::<f64>("a-b -2.5e3"), Some((-2500.0, 4)));
+    assert_eq!(find_first_float::<f64>("€.5 off"), Some((0.5, 3)));
+    assert_eq!(find_first_float::<f64>("v1.2.3"), Some((1.2, 1)));
+    assert_eq!(find_first_float::<f64>("+-5"), Some((-5.0, 1)));
+    assert_eq!(find_first_float::<f64>("a-b"), None);
+    assert_eq!(find_first_float::<f64>("no . number"), None);
+    assert_eq!(find_first_float::<f64>(""), None);
+}
+
+#[test]
+fn test_find_all_floats() {
+    assert_eq!(
+        find_all_floats::<f64>("from 1.5 to 2.5e1 km", false),
+        vec![(1.5, 5), (25.0, 12)]
+    );
+    assert_eq!(
+        find_all_floats::<f64>("3-4", false),
+        vec![(3.0, 0), (-4.0, 1)]
+    );
+    assert_eq!(
+        find_all_floats::<f64>("3-4", true),
+        vec![(3.0, 0), (4.0, 2)]
+    );
+    assert_eq!(
+        find_all_floats::<f64>("x-1 3 -4", true),
+        vec![(1.0, 2), (3.0, 4), (-4.0, 6)]
+    );
+    assert_eq!(
+        find_all_floats::<f64>("1.2.3", false),
+        vec![(1.2, 0), (0.3, 3)]
+    );
+    assert_eq!(find_all_floats::<f64>("none", false), vec![]);
+}
+
+#[test]
+fn test_parse_float_exponent_base() {
+    let options = ParseOptions {
+        exponent_base: 2,
+        ..Default::default()
+    };
+
+    let parse_f64 =
+        |s: &str| parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &options);
+
+    assert_eq!(parse_f64("1.5e3"), Some(12.0));
+    assert_eq!(parse_f64("-1.5e-2"), Some(-0.375));
+    assert_eq!(parse_f64("1.5"), Some(1.5));
+    assert_eq!(parse_f64("0e9999"), Some(0.0));
+    assert_eq!(parse_f64("1e1024"), Some(f64::INFINITY));
+    assert_eq!(parse_float::<f64>("1.5e3"), Some(1500.0));
+}
+
+#[test]
+fn test_parse_float_capped() {
+    assert_eq!(
+        parse_float_capped::<f64>("1.2345678", 4, true),
+        Some(1.2346)
+    );
+    assert_eq!(
+        parse_float_capped::<f64>("1.2344999", 4, true),
+        Some(1.2345)
+    );
+    assert_eq!(
+        parse_float_capped::<f64>("-9.99995e2", 4, true),
+        Some(-1000.0)
+    );
+    assert_eq!(parse_float_capped::<f64>(".5", 0, true), Some(1.0));
+    assert_eq!(parse_float_capped::<f64>("2.5", 4, true), Some(2.5));
+
+    assert_eq!(
+        parse_float_capped::<f64>("1.2345678", 4, false),
+        Some(1.2345)
+    );
+    assert_eq!(
+        parse_float_capped::<f64>("1.2344999", 4, false),
+        Some(1.2344)
+    );
+    assert_eq!(
+        parse_float_capped::<f64>("-9.99995e2", 4, false),
+        Some(-999.99)
+    );
+    assert_eq!(parse_float_capped::<f64>(".5", 0, false), Some(0.0));
+    assert_eq!(parse_float_capped::<f64>("2.5", 4, false), Some(2.5));
+
+    let options = ParseOptions {
+        max_frac_digits: Some(4),
+        ..Default::default()
+    };
+
+    let mut chars = "1.2345678;".chars().peekable();
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut chars, &options),
+        Some(1.2345)
+    );
+    assert_eq!(chars.next(), Some(';'));
+}
+
+#[test]
+fn test_parse_float_prefixed() {
+    let options = ParseOptions {
+        prefixed_floats: true,
+        radix_prefixes: true,
+        ..Default::default()
+    };
+
+    let parse_f64 = |s: &str| {
+        parse_float_from_iter_internal::<f64>(&mut Replay::new(&mut s.chars().peekable()), &options)
+    };
+
+    assert_eq!(parse_float::<f64>("0x10"), Some(0.0));
+    assert_eq!(parse_f64("0x10"), Ok(16.0));
+    assert_eq!(parse_f64("-0XfF"), Ok(-255.0));
+    assert_eq!(parse_f64("0o17"), Ok(15.0));
+    assert_eq!(parse_f64("0b101"), Ok(5.0));
+    assert_eq!(parse_f64("0d42"), Ok(42.0));
+    assert_eq!(parse_f64("0x1.8"), Ok(1.0));
+    assert_eq!(parse_f64("0.5"), Ok(0.5));
+    assert_eq!(parse_f64("0"), Ok(0.0));
+    assert_eq!(parse_f64("0x"), Err(ParseError::InvalidDigit));
+}
+
+#[test]
+fn test_parse_float_as_int() {
+    assert_eq!(
+        parse_float_as_int::<i64>("3.7", RoundingMode::Nearest),
+        Some(4)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("3.7", RoundingMode::Floor),
+        Some(3)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("3.2", RoundingMode::Ceil),
+        Some(4)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("-3.7", RoundingMode::Trunc),
+        Some(-3)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("-2.5", RoundingMode::Nearest),
+        Some(-3)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("1e3", RoundingMode::Trunc),
+        Some(1000)
+    );
+    assert_eq!(
+        parse_float_as_int::<u8>("255.4", RoundingMode::Nearest),
+        Some(255)
+    );
+    assert_eq!(
+        parse_float_as_int::<u8>("255.5", RoundingMode::Nearest),
+        None
+    );
+    assert_eq!(
+        parse_float_as_int::<u8>("-0.5", RoundingMode::Ceil),
+        Some(0)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("9007199254740993", RoundingMode::Trunc),
+        Some(9007199254740993)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("-9223372036854775808.4", RoundingMode::Nearest),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("-9223372036854775807.1", RoundingMode::Floor),
+        Some(i64::MIN)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("9223372036854775807.1", RoundingMode::Ceil),
+        None
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("0.05", RoundingMode::Nearest),
+        Some(0)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("0.001", RoundingMode::Ceil),
+        Some(1)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("-0.001", RoundingMode::Floor),
+        Some(-1)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("1.5e-1", RoundingMode::Nearest),
+        Some(0)
+    );
+    assert_eq!(
+        parse_float_as_int::<i64>("2.5e1", RoundingMode::Nearest),
+        Some(25)
+    );
+    assert_eq!(
+        parse_float_as_int::<u8>("-0.5", RoundingMode::Nearest),
+        None
+    );
+    assert_eq!(parse_float_as_int::<i64>("inf", RoundingMode::Floor), None);
+    assert_eq!(parse_float_as_int::<i64>("nan", RoundingMode::Floor), None);
+}
+
+#[test]
+fn test_parse_decimal_parts() {
+    assert_eq!(
+        parse_decimal_parts(" -012.340e-5x"),
+        Some(DecimalParts {
+            negative: true,
+            int_digits: vec![0, 1, 2],
+            frac_digits: vec![3, 4, 0],
+            exp: -5,
+        })
+    );
+    assert_eq!(
+        parse_decimal_parts(".5"),
+        Some(DecimalParts {
+            negative: false,
+            int_digits: vec![],
+            frac_digits: vec![5],
+            exp: 0,
+        })
+    );
+    assert_eq!(parse_decimal_parts("inf"), None);
+    assert_eq!(parse_decimal_parts("."), None);
+}
+
+#[test]
+fn test_parse_float_best_effort() {
+    assert_eq!(parse_float_with_rest::<f64>("12."), Some((12.0, "")));
+    assert_eq!(parse_float_with_rest::<f64>("12e"), Some((12.0, "e")));
+    assert_eq!(parse_float_with_rest::<f64>("12e-"), Some((12.0, "e-")));
+    assert_eq!(parse_float_with_rest::<f64>("12E+x"), Some((12.0, "E+x")));
+    assert_eq!(
+        parse_float_with_rest::<f64>("12.34.56"),
+        Some((12.34, ".56"))
+    );
+    assert_eq!(
+        parse_float_with_rest::<f64>("12.e2e3"),
+        Some((1200.0, "e3"))
+    );
+    assert_eq!(parse_float_with_rest::<f64>("--5"), None);
+    assert_eq!(parse_float_with_rest::<f64>("-.e5"), None);
+}
+
+#[test]
+fn test_parse_float_custom_type() {
+    use std::num::FpCategory;
+    use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+    /// Float type which is opaque to the parser, delegating to f64.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Mock(f64);
+
+    macro_rules! ops {
+        ($($trait:ident::$fn:ident),*) => {
+            $(impl $trait for Mock {
+                type Output = Mock;
+                fn $fn(self, other: Mock) -> Mock {
+                    Mock(self.0.$fn(other.0))
+                }
+            })*
+        };
+    }
+
+    macro_rules! delegate {
+        ($($fn:ident($($arg:ident),*) -> $ret:ty),*) => {
+            $(fn $fn(self, $($arg: Self),*) -> $ret {
+                self.0.$fn($($arg.0),*).into()
+            })*
+        };
+    }
+
+    ops!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+    impl From<f64> for Mock {
+        fn from(value: f64) -> Self {
+            Mock(value)
+        }
+    }
+
+    impl Neg for Mock {
+        type Output = Mock;
+        fn neg(self) -> Mock {
+            Mock(-self.0)
+        }
+    }
+
+    impl num::Zero for Mock {
+        fn zero() -> Self {
+            Mock(0.0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0.0
+        }
+    }
+
+    impl num::One for Mock {
+        fn one() -> Self {
+            Mock(1.0)
+        }
+    }
+
+    impl num::Num for Mock {
+        type FromStrRadixErr = <f64 as num::Num>::FromStrRadixErr;
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            f64::from_str_radix(s, radix).map(Mock)
+        }
+    }
+
+    impl num::ToPrimitive for Mock {
+        fn to_i64(&self) -> Option<i64> {
+            self.0.to_i64()
+        }
+        fn to_u64(&self) -> Option<u64> {
+            self.0.to_u64()
+        }
+    }
+
+    impl num::NumCast for Mock {
+        fn from<T: num::ToPrimitive>(n: T) -> Option<Self> {
+            n.to_f64().map(Mock)
+        }
+    }
+
+    impl num::Float for Mock {
+        fn nan() -> Self {
+            Mock(f64::NAN)
+        }
+        fn infinity() -> Self {
+            Mock(f64::INFINITY)
+        }
+        fn neg_infinity() -> Self {
+            Mock(f64::NEG_INFINITY)
+        }
+        fn neg_zero() -> Self {
+            Mock(-0.0)
+        }
+        fn min_value() -> Self {
+            Mock(f64::MIN)
+        }
+        fn min_positive_value() -> Self {
+            Mock(f64::MIN_POSITIVE)
+        }
+        fn max_value() -> Self {
+            Mock(f64::MAX)
+        }
+        fn classify(self) -> FpCategory {
+            self.0.classify()
+        }
+        fn powi(self, n: i32) -> Self {
+            Mock(self.0.powi(n))
+        }
+        fn sin_cos(self) -> (Self, Self) {
+            (Mock(self.0.sin()), Mock(self.0.cos()))
+        }
+        fn integer_decode(self) -> (u64, i16, i8) {
+            num::Float::integer_decode(self.0)
+        }
+
+        delegate!(
+            is_nan() -> bool, is_infinite() -> bool, is_finite() -> bool, is_normal() -> bool,
+            is_sign_positive() -> bool, is_sign_negative() -> bool,
+            floor() -> Self, ceil() -> Self, round() -> Self, trunc() -> Self, fract() -> Self,
+            abs() -> Self, signum() -> Self, recip() -> Self, sqrt() -> Self, cbrt() -> Self,
+            exp() -> Self, exp2() -> Self, exp_m1() -> Self, ln() -> Self, ln_1p() -> Self,
+            log2() -> Self, log10() -> Self, sin() -> Self, cos() -> Self, tan() -> Self,
+            asin() -> Self, acos() -> Self, atan() -> Self, sinh() -> Self, cosh() -> Self,
+            tanh() -> Self, asinh() -> Self, acosh() -> Self, atanh() -> Self,
+            mul_add(a, b) -> Self, powf(n) -> Self, log(base) -> Self, max(other) -> Self,
+            min(other) -> Self, hypot(other) -> Self, atan2(other) -> Self
+        );
+
+        #[allow(deprecated)]
+        fn abs_sub(self, other: Self) -> Self {
+            Mock(num::Float::abs_sub(self.0, other.0))
+        }
+    }
+
+    assert_eq!(parse_float::<Mock>(" -1.25e2"), Some(Mock(-125.0)));
+    assert_eq!(parse_float::<Mock>("inf"), Some(Mock(f64::INFINITY)));
+    assert!(parse_float::<Mock>("nan").is_some_and(|nan| nan.0.is_nan()));
+    assert_eq!(parse_float_capped::<Mock>("2.55", 1, true), Some(Mock(2.6)));
+    assert_eq!(
+        parse_float_from_iter_with_options::<Mock>(
+            &mut "1.5e3".chars().peekable(),
+            &ParseOptions {
+                exponent_base: 2,
+                ..Default::default()
+            }
+        ),
+        Some(Mock(12.0))
+    );
+}
+
+#[test]
+fn test_parse_float_bounded_len() {
+    assert_eq!(parse_float_bounded_len::<f64>("-1.5e+3", 7), Some(-1500.0));
+    assert_eq!(parse_float_bounded_len::<f64>("-1.5e+3", 6), None);
+    assert_eq!(parse_float_bounded_len::<f64>("    1.5", 3), Some(1.5));
+    assert_eq!(parse_float_bounded_len::<f64>(" - 1.5", 4), None);
+    assert_eq!(parse_float_bounded_len::<f64>("1.5", usize::MAX), Some(1.5));
+    assert_eq!(parse_float_bounded_len::<f64>("1.5 and more", 3), Some(1.5));
+    assert_eq!(parse_float_bounded_len::<f64>("1ex", 1), None);
+    assert_eq!(parse_float_bounded_len::<f64>("1ex", 2), Some(1.0));
+    assert_eq!(
+        parse_float_bounded_len::<f64>("inf", 3),
+        Some(f64::INFINITY)
+    );
+    assert_eq!(parse_float_bounded_len::<f64>("", 32), None);
+    assert_eq!(parse_float_bounded_len::<f64>(&"1".repeat(10000), 32), None);
+    assert_eq!(
+        parse_float_bounded_len::<f64>(&"1".repeat(32), 32),
+        Some(11111111111111111111111111111111.0)
+    );
+}
+
+#[test]
+fn test_parse_float_smart() {
+    assert_eq!(parse_float_smart::<f64>("1.234,56"), Some(1234.56));
+    assert_eq!(parse_float_smart::<f64>("1,234.56"), Some(1234.56));
+    assert_eq!(
+        parse_float_smart::<f64>(" -1.234.567,5 EUR"),
+        Some(-1234567.5)
+    );
+    assert_eq!(parse_float_smart::<f64>("1,234,567"), Some(1234567.0));
+    assert_eq!(parse_float_smart::<f64>("1,5"), Some(1.5));
+    assert_eq!(parse_float_smart::<f64>("1,234"), Some(1.234));
+    assert_eq!(parse_float_smart::<f64>("1234"), Some(1234.0));
+    assert_eq!(parse_float_smart::<f64>("1,5."), Some(1.5));
+    assert_eq!(parse_float_smart::<f64>("inf"), Some(f64::INFINITY));
+    assert_eq!(parse_float_smart::<f64>(","), None);
+}
+
+#[test]
+fn test_parse_float_full() {
+    assert_eq!(parse_float_full::<f64>(" 1.5e3 m"), Ok((1500.0, 6)));
+    assert_eq!(
+        parse_float_full::<f64>("-€"),
+        Err((ParseError::InvalidDigit, 1))
+    );
+    assert_eq!(
+        parse_float_full::<f64>("  €1"),
+        Err((ParseError::InvalidDigit, 2))
+    );
+    assert_eq!(
+        parse_float_full::<f64>("½ 2"),
+        Err((ParseError::InvalidDigit, 0))
+    );
+    assert_eq!(parse_float_full::<f64>("2½"), Ok((2.0, 1)));
+    assert_eq!(parse_float_full::<f64>(" "), Err((ParseError::Empty, 1)));
+}
+
+#[test]
+fn test_parse_float_trimmed() {
+    let s = " 1.5e3 \t m";
+    let (value, consumed) = parse_float_trimmed::<f64>(s).unwrap();
+
+    assert_eq!(value, 1500.0);
+    assert_eq!(
+        consumed,
+        Consumed {
+            number_len: 6,
+            trailing_ws_len: 3
+        }
+    );
+    assert_eq!(&s[consumed.number_len..], " \t m");
+    assert_eq!(&s[consumed.number_len + consumed.trailing_ws_len..], "m");
+
+    assert_eq!(
+        parse_float_trimmed::<f64>("2\u{a0}"),
+        Ok((
+            2.0,
+            Consumed {
+                number_len: 1,
+                trailing_ws_len: 2
+            }
+        ))
+    );
+    assert_eq!(
+        parse_float_trimmed::<f64>("-1"),
+        Ok((
+            -1.0,
+            Consumed {
+                number_len: 2,
+                trailing_ws_len: 0
+            }
+        ))
+    );
+    assert_eq!(parse_float_trimmed::<f64>(" "), Err((ParseError::Empty, 1)));
+}
+
+#[test]
+fn test_parse_float_locale() {
+    let parse = |s: &str, locale: Locale| {
+        parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &locale.into())
+    };
+
+    assert_eq!(parse("1,234.5", Locale::EnUs), Some(1234.5));
+    assert_eq!(parse("1.234,5", Locale::DeDe), Some(1234.5));
+    assert_eq!(parse("1,5", Locale::EnUs), Some(1.0));
+    assert_eq!(parse("1,5", Locale::DeDe), Some(1.5));
+    assert_eq!(parse("1.234", Locale::EnUs), Some(1.234));
+    assert_eq!(parse("1.234", Locale::DeDe), Some(1234.0));
+    assert_eq!(parse("1,2345", Locale::EnUs), Some(1.0));
+    assert_eq!(parse("1234,567", Locale::EnUs), Some(1234.0));
+    assert_eq!(parse("1,234,567", Locale::EnUs), Some(1234567.0));
+    assert_eq!(parse("1,234,56", Locale::EnUs), Some(1234.0));
+
+    let options = ParseOptions {
+        terminators: Some(vec![]),
+        ..Locale::EnUs.into()
+    };
+
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "1,5".chars().peekable(), &options),
+        None
+    );
+}
+
+#[test]
+fn test_parse_float_locale_pushback() {
+    // Separators and groups failing validation stay in the caller's iterator
+    for (s, locale, expected, rest) in [
+        ("1,23x", Locale::EnUs, 1.0, ",23x"),
+        ("1,234,56", Locale::EnUs, 1234.0, ",56"),
+        ("1,234,567", Locale::Indian, 1234.0, ",567"),
+    ] {
+        let mut chars = Pushback::new(s.chars());
+        assert_eq!(
+            parse_float_from_iter_with_options::<f64>(&mut chars, &locale.into()),
+            Some(expected)
+        );
+        assert_eq!(chars.collect::<String>(), rest, "{s}");
+    }
+}
+
+#[test]
+fn test_parse_float_locale_indian() {
+    let parse = |s: &str| {
+        parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &Locale::Indian.into())
+    };
+
+    assert_eq!(parse("12,34,567.5"), Some(1234567.5));
+    assert_eq!(parse("1,23,456"), Some(123456.0));
+    assert_eq!(parse("1,234"), Some(1234.0));
+    assert_eq!(parse("123456"), Some(123456.0));
+    assert_eq!(parse("1,234,56"), Some(1234.0));
+    assert_eq!(parse("1,234,567"), Some(1234.0));
+    assert_eq!(parse("1,23,45"), Some(1.0));
+    assert_eq!(parse("123,456"), Some(123.0));
+}
+
+#[test]
+fn test_parse_float_locale_dede() {
+    let parse = |s: &str| {
+        parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &Locale::DeDe.into())
+    };
+
+    assert_eq!(parse("-12.345.678"), Some(-12345678.0));
+    assert_eq!(parse("1.234.567,89"), Some(1234567.89));
+    assert_eq!(parse("-1.000.000.000,5"), Some(-1000000000.5));
+    assert_eq!(parse("1.234,56,7"), Some(1234.56));
+    assert_eq!(parse("1.234,567.890"), Some(1234.567));
+    assert_eq!(parse("1,234.567"), Some(1.234));
+
+    let options = ParseOptions {
+        terminators: Some(vec![]),
+        ..Locale::DeDe.into()
+    };
+
+    for (s, expected) in [
+        ("1.234.567,89", Some(1234567.89)),
+        ("1.234,56,7", None),
+        ("1.234,567.890", None),
+        ("1,234.567", None),
+        ("1.23,5", None),
+    ] {
+        assert_eq!(
+            parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &options),
+            expected,
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_float_decimal_comma_exponent() {
+    let parse = |s: &str| {
+        parse_float_from_iter_with_options::<f64>(&mut s.chars().peekable(), &Locale::DeDe.into())
+    };
+
+    assert_eq!(parse("1,5E3"), Some(1500.0));
+    assert_eq!(parse("-1.234,5e-2"), Some(-12.345));
+    assert_eq!(parse(",5e1"), Some(5.0));
+    assert_eq!(parse("1,e2"), Some(100.0));
+    assert_eq!(parse("1.5E3"), Some(1.0));
+}
+
+#[test]
+fn test_parse_float_spaced_separator_ends() {
+    let options = ParseOptions {
+        spaced_separator_ends: true,
+        ..Locale::DeDe.into()
+    };
+    let mut chars = Pushback::new("1,5, 2,5,\t-3, 4".chars());
+    let mut args = Vec::new();
+
+    loop {
+        args.push(parse_float_from_iter_with_options::<f64>(&mut chars, &options).unwrap());
+
+        if chars.next() != Some(',') {
+            break;
+        }
+    }
+
+    assert_eq!(args, [1.5, 2.5, -3.0, 4.0]);
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(&mut "1,".chars().peekable(), &options),
+        Some(1.0)
+    );
+    assert_eq!(
+        parse_float_from_iter_with_options::<f64>(
+            &mut "1, 5".chars().peekable(),
+            &Locale::DeDe.into()
+        ),
+        Some(1.0)
+    );
+}
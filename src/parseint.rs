@@ -1,13 +1,55 @@
 //! JavaScript-style parseInt-like parsing of numbers from strings in Rust.
 use super::*;
-use num;
 
-/// Internal function to parse uint values from a char-iterator with a given radix.
-fn parse_uint_internal<T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive>(
-    chars: &mut dyn PeekableIterator<Item = char>,
+/// Internal function to parse a run of digits with a given radix.
+///
+/// Grouping separators configured by the options are accepted between digits.
+/// Returns the accumulated value and the number of digits.
+///
+/// When `neg` is set, the value is accumulated negatively, so the minimum of a signed type
+/// can be reached.
+fn parse_digits_internal<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    chars: &mut Replay,
+    radix: u32,
+    neg: bool,
+    options: &ParseOptions,
+) -> Result<(T, u32), ParseError> {
+    let mul = T::from_u32(radix).unwrap();
+    let mut ret = Some(T::zero()); // None on overflow
+
+    let count = options.scan_digits(chars, radix, |dig| {
+        let digit = dig.to_digit(radix).unwrap();
+
+        // A negative digit doesn't exist for unsigned types, so it overflows as well
+        let digit = if neg {
+            T::from_i64(-(digit as i64))
+        } else {
+            T::from_u32(digit)
+        };
+
+        ret = ret
+            .take()
+            .zip(digit)
+            .and_then(|(ret, digit)| ret.checked_mul(&mul)?.checked_add(&digit));
+    });
+
+    match ret {
+        _ if count == 0 => Err(ParseError::InvalidDigit),
+        Some(ret) => Ok((ret, count as u32)),
+        None => Err(ParseError::Overflow { positive: !neg }),
+    }
+}
+
+/// Internal function to detect the radix from a prefix, when not given.
+///
+/// Returns the radix, and whether a leading `0` was consumed which is a valid number on its own.
+fn parse_radix_internal(
+    chars: &mut Replay,
     mut radix: Option<u32>,
-) -> Option<T> {
-    let mut ret = T::zero();
+    options: &ParseOptions,
+) -> (u32, bool) {
     let mut any = false;
 
     if radix.is_none() {
@@ -15,64 +57,197 @@ fn parse_uint_internal<T: num::Integer + num::CheckedAdd + num::CheckedMul + num
             chars.next();
             any = true;
 
-            match chars.peek() {
-                Some('x') | Some('X') => {
-                    radix = Some(16);
-                    chars.next();
-                    any = false; // expecting a hex number!
-                }
-                _ => {}
+            radix = chars.peek().and_then(|ch| options.radix_prefix(*ch));
+
+            if radix.is_some() {
+                chars.next();
+                any = false; // expecting digits of the prefixed radix!
             }
         }
     }
 
-    let radix = radix.unwrap_or(10);
+    (radix.unwrap_or(10), any)
+}
+
+/// Internal function to parse uint values from a char-iterator with a given radix.
+///
+/// Returns the value and the radix used, which is detected from a prefix when not given.
+/// When `neg` is set, the value is negated, which requires T to be signed.
+fn parse_uint_internal<T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive>(
+    chars: &mut Replay,
+    radix: Option<u32>,
+    neg: bool,
+    options: &ParseOptions,
+) -> Result<(T, u32), ParseError> {
+    let (radix, any) = parse_radix_internal(chars, radix, options);
 
-    while let Some(dig) = chars.peek() {
-        match dig.to_digit(radix) {
-            Some(digit) => {
-                ret = ret.checked_mul(&T::from_u32(radix).unwrap()).unwrap();
-                ret = ret.checked_add(&T::from_u32(digit).unwrap()).unwrap();
+    match parse_digits_internal::<T>(chars, radix, neg, options) {
+        Ok((ret, _)) => Ok((ret, radix)),
+        Err(ParseError::InvalidDigit) if any => Ok((T::zero(), radix)),
+        Err(err) => Err(err),
+    }
+}
 
-                chars.next();
-                any = true;
-            }
-            None => break,
+/// Internal function to apply an exponent behind a decimal int value, if enabled by the options.
+fn parse_exponent_internal<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    chars: &mut Replay,
+    mut ret: T,
+    radix: u32,
+    options: &ParseOptions,
+) -> Result<T, ParseError> {
+    if !options.int_exponent || radix != 10 {
+        return Ok(ret);
+    }
+
+    let Some(marker @ ('e' | 'E')) = chars.peek().copied() else {
+        return Ok(ret);
+    };
+
+    chars.next();
+
+    // A signed exponent isn't integral or makes no sense for ints
+    if let Some('+' | '-') = chars.peek() {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    let mut exp = 0u32;
+    let mut any = false;
+
+    while let Some(digit) = chars.peek().and_then(|dig| dig.to_digit(10)) {
+        exp = exp.saturating_mul(10).saturating_add(digit);
+        any = true;
+        chars.next();
+    }
+
+    // An exponent without digits is not part of the number
+    if !any {
+        chars.unread(&[marker]);
+        return Ok(ret);
+    }
+
+    let overflow = ParseError::Overflow {
+        positive: ret > T::zero(),
+    };
+    let ten = T::from_u32(10).unwrap();
+
+    for _ in 0..exp {
+        if ret.is_zero() {
+            break;
         }
+
+        ret = ret.checked_mul(&ten).ok_or(overflow.clone())?;
     }
 
-    if any {
-        Some(ret)
-    } else {
-        None
+    Ok(ret)
+}
+
+/// Internal function to reinterpret a value filling the bit width of T as two's complement.
+///
+/// The width of T is probed by the values it can represent, so this fails for types
+/// without a fixed width.
+fn twos_complement<T: num::FromPrimitive>(value: u128) -> Option<T> {
+    for bits in [8, 16, 32, 64, 128] {
+        let min = i128::MIN >> (128 - bits);
+
+        if T::from_i128(min).is_none() || (bits < 128 && T::from_i128(min - 1).is_some()) {
+            continue;
+        }
+
+        if bits == 128 {
+            return T::from_i128(value as i128);
+        } else if value >> bits == 0 {
+            return T::from_i128(value as i128 - (1 << bits));
+        }
+
+        return None;
+    }
+
+    None
+}
+
+/// Parse a run of digits with a given radix from an iterator.
+///
+/// This is the lowest-level building block, which accepts neither whitespace, sign,
+/// radix prefix nor decimal point. Returns the accumulated value and the number of digits,
+/// or None when no digit was found or the value overflows.
+pub fn parse_digits(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    radix: u32,
+) -> Option<(u128, u32)> {
+    parse_digits_internal::<u128>(
+        &mut Replay::new(chars),
+        radix,
+        false,
+        &ParseOptions::default(),
+    )
+    .ok()
+}
+
+/// Internal function to parse uint values from an iterator with a given radix and options.
+pub(crate) fn parse_uint_from_iter_internal<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    chars: &mut Replay,
+    radix: Option<u32>,
+    options: &ParseOptions,
+) -> Result<T, ParseError> {
+    let prefix = options.parse_prefix(chars, false);
+    let (ret, radix) = parse_uint_internal::<T>(chars, radix, false, options)?;
+    let ret = parse_exponent_internal(chars, ret, radix, options)?;
+
+    if (options.reject_zero && ret.is_zero())
+        || !options.parse_type_suffix::<T>(chars)
+        || !options.parse_suffix(chars, &prefix)
+    {
+        return Err(ParseError::InvalidDigit);
     }
+
+    Ok(ret)
+}
+
+/// Parse uint values from an iterator with a given radix and options.
+///
+/// Whitespace is skipped in front of the sign only, if enabled by the options;
+/// whitespace between the sign and the first digit is never accepted.
+/// Characters are consumed even when parsing fails, use try_parse_uint_peek() to avoid this.
+/// Characters read ahead behind the number are only kept in iterators supporting
+/// PeekableIterator::put_back(), like a Pushback.
+pub fn parse_uint_from_iter_with_options<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    radix: Option<u32>,
+    options: &ParseOptions,
+) -> Option<T> {
+    parse_uint_from_iter_internal(&mut Replay::new(chars), radix, options).ok()
 }
 
 /// Parse uint values from an iterator with a given radix.
+///
+/// When `whitespace` is set, whitespace is skipped in front of the sign only;
+/// whitespace between the sign and the first digit is never accepted.
 pub fn parse_uint_from_iter_with_radix<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
 >(
     chars: &mut dyn PeekableIterator<Item = char>,
     radix: Option<u32>,
     whitespace: bool,
 ) -> Option<T> {
-    while let Some(ch) = chars.peek() {
-        if whitespace && ch.is_whitespace() {
-            chars.next();
-            continue;
-        } else if *ch == '+' {
-            chars.next();
-        }
-
-        break;
-    }
-
-    parse_uint_internal::<T>(chars, radix)
+    parse_uint_from_iter_with_options(
+        chars,
+        radix,
+        &ParseOptions {
+            whitespace,
+            ..Default::default()
+        },
+    )
 }
 
 /// Parse decimal uint values from an iterator.
 pub fn parse_uint_from_iter<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
 >(
     chars: &mut dyn PeekableIterator<Item = char>,
     whitespace: bool,
@@ -80,54 +255,189 @@ pub fn parse_uint_from_iter<
     parse_uint_from_iter_with_radix(chars, None, whitespace)
 }
 
+/// Internal function to parse int values from an iterator with a given radix and options.
+pub(crate) fn parse_int_from_iter_internal<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    chars: &mut Replay,
+    radix: Option<u32>,
+    options: &ParseOptions,
+) -> Result<T, ParseError> {
+    let prefix = options.parse_prefix(chars, true);
+
+    let (ret, radix) = if options.radix_twos_complement && !prefix.neg {
+        let (value, radix) = parse_uint_internal::<u128>(chars, radix, false, options)?;
+
+        let ret = match T::from_u128(value) {
+            Some(ret) => ret,
+            None if radix != 10 => {
+                twos_complement::<T>(value).ok_or(ParseError::Overflow { positive: true })?
+            }
+            None => return Err(ParseError::Overflow { positive: true }),
+        };
+
+        (ret, radix)
+    } else {
+        parse_uint_internal::<T>(chars, radix, prefix.neg, options)?
+    };
+
+    let ret = parse_exponent_internal(chars, ret, radix, options)?;
+
+    if (options.reject_zero && ret.is_zero())
+        || !options.parse_type_suffix::<T>(chars)
+        || !options.parse_suffix(chars, &prefix)
+    {
+        return Err(ParseError::InvalidDigit);
+    }
+
+    Ok(ret)
+}
+
+/// Parse int values from an iterator with a given radix and options.
+///
+/// Whitespace is skipped in front of the sign only, if enabled by the options;
+/// whitespace between the sign and the first digit is never accepted.
+/// Characters are consumed even when parsing fails, use try_parse_int_peek() to avoid this.
+/// Characters read ahead behind the number are only kept in iterators supporting
+/// PeekableIterator::put_back(), like a Pushback.
+pub fn parse_int_from_iter_with_options<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    radix: Option<u32>,
+    options: &ParseOptions,
+) -> Option<T> {
+    parse_int_from_iter_internal(&mut Replay::new(chars), radix, options).ok()
+}
+
 /// Parse int values from an iterator with a given radix.
+///
+/// When `whitespace` is set, whitespace is skipped in front of the sign only;
+/// whitespace between the sign and the first digit is never accepted.
 pub fn parse_int_from_iter_with_radix<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
 >(
     chars: &mut dyn PeekableIterator<Item = char>,
     radix: Option<u32>,
     whitespace: bool,
 ) -> Option<T> {
-    let mut neg = false;
+    parse_int_from_iter_with_options::<T>(
+        chars,
+        radix,
+        &ParseOptions {
+            whitespace,
+            ..Default::default()
+        },
+    )
+}
 
-    while let Some(ch) = chars.peek() {
-        if whitespace && ch.is_whitespace() {
-            chars.next();
-            continue;
-        }
+/// Parse decimal int values from an iterator.
+pub fn parse_int_from_iter<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    whitespace: bool,
+) -> Option<T> {
+    parse_int_from_iter_with_radix::<T>(chars, None, whitespace)
+}
 
-        if *ch == '+' || *ch == '-' {
-            neg = *ch == '-';
-            chars.next();
-        }
+/// Try to parse uint values from a cloneable iterator with a given radix and options.
+///
+/// The iterator is only advanced on success, and then exactly by the characters of the number,
+/// so it can be restored for another attempt on failure.
+pub fn try_parse_uint_peek<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+    I: PeekableIterator<Item = char> + Clone,
+>(
+    chars: &mut I,
+    radix: Option<u32>,
+    options: &ParseOptions,
+) -> Result<T, ParseError> {
+    let mut ahead = chars.clone();
+    let mut ahead = Replay::new(&mut ahead);
+    let ret = parse_uint_from_iter_internal(&mut ahead, radix, options)?;
 
-        break;
+    for _ in 0..ahead.consumed() {
+        chars.next();
     }
 
-    if let Some(ret) = parse_uint_internal::<T>(chars, radix) {
-        if neg {
-            Some(-ret)
-        } else {
-            Some(ret)
-        }
-    } else {
-        None
+    Ok(ret)
+}
+
+/// Try to parse int values from a cloneable iterator with a given radix and options.
+///
+/// The iterator is only advanced on success, and then exactly by the characters of the number,
+/// so it can be restored for another attempt on failure.
+pub fn try_parse_int_peek<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+    I: PeekableIterator<Item = char> + Clone,
+>(
+    chars: &mut I,
+    radix: Option<u32>,
+    options: &ParseOptions,
+) -> Result<T, ParseError> {
+    let mut ahead = chars.clone();
+    let mut ahead = Replay::new(&mut ahead);
+    let ret = parse_int_from_iter_internal(&mut ahead, radix, options)?;
+
+    for _ in 0..ahead.consumed() {
+        chars.next();
     }
+
+    Ok(ret)
 }
 
-/// Parse decimal int values from an iterator.
-pub fn parse_int_from_iter<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+/// Parse int values from an iterator with a given radix, requiring the number to be its content.
+///
+/// Apart from whitespace, the iterator must not yield anything behind the number.
+pub fn parse_int_from_iter_strict<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
 >(
     chars: &mut dyn PeekableIterator<Item = char>,
-    whitespace: bool,
+    radix: Option<u32>,
 ) -> Option<T> {
-    parse_int_from_iter_with_radix::<T>(chars, None, whitespace)
+    let ret = parse_int_from_iter_with_radix::<T>(chars, radix, true)?;
+
+    for ch in chars {
+        if !ch.is_whitespace() {
+            return None;
+        }
+    }
+
+    Some(ret)
+}
+
+/// Int value of a signedness decided at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntValue {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+/// Parse decimal int values from an iterator, with the signedness decided at runtime.
+///
+/// Returns an IntValue::Signed when `signed` is set, and an IntValue::Unsigned otherwise.
+/// Values overflowing the 128-bit representation are rejected.
+pub fn parse_int_value_from_iter(
+    chars: &mut dyn PeekableIterator<Item = char>,
+    signed: bool,
+    whitespace: bool,
+) -> Option<IntValue> {
+    if signed {
+        parse_int_from_iter::<i128>(chars, whitespace).map(IntValue::Signed)
+    } else {
+        parse_uint_from_iter::<u128>(chars, whitespace).map(IntValue::Unsigned)
+    }
+}
+
+/// Parse decimal int values from a &str, with the signedness decided at runtime.
+pub fn parse_int_value(s: &str, signed: bool) -> Option<IntValue> {
+    parse_int_value_from_iter(&mut s.chars().peekable(), signed, true)
 }
 
 /// Parse uint values from a &str with a given radix.
 pub fn parse_uint_with_radix<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
 >(
     s: &str,
     radix: u32,
@@ -136,15 +446,58 @@ pub fn parse_uint_with_radix<
 }
 
 /// Parse decimal uint values from a &str.
-pub fn parse_uint<T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive>(
+pub fn parse_uint<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
     s: &str,
 ) -> Option<T> {
     parse_uint_from_iter_with_radix::<T>(&mut s.chars().peekable(), None, true)
 }
 
+/// Parse decimal u64 values from a &str in a `const` context.
+///
+/// This is a limited variant of parse_uint(), because traits and iterators can't be used in
+/// `const fn`. Only ASCII whitespace is skipped in front of the number, radix prefixes and
+/// options aren't supported, and the only target type is u64. Like parse_uint(), parsing stops
+/// at the first non-digit, so `"42px"` is accepted as `42`.
+pub const fn parse_u64_const(s: &str) -> Option<u64> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+
+    if i < bytes.len() && bytes[i] == b'+' {
+        i += 1;
+    }
+
+    let start = i;
+    let mut ret: u64 = 0;
+
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        ret = match ret.checked_mul(10) {
+            Some(ret) => ret,
+            None => return None,
+        };
+        ret = match ret.checked_add((bytes[i] - b'0') as u64) {
+            Some(ret) => ret,
+            None => return None,
+        };
+
+        i += 1;
+    }
+
+    if i == start {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
 /// Parse int values from a &str with a given radix.
 pub fn parse_int_with_radix<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
 >(
     s: &str,
     radix: u32,
@@ -154,74 +507,1594 @@ pub fn parse_int_with_radix<
 
 /// Parse decimal int values from a &str.
 pub fn parse_int<
-    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
 >(
     s: &str,
 ) -> Option<T> {
     parse_int_from_iter_with_radix::<T>(&mut s.chars().peekable(), None, true)
 }
 
-#[test]
-fn test_parse_uint_i64() {
-    assert_eq!(parse_uint::<i64>(" 123hello "), Some(123i64));
-    assert_eq!(parse_uint::<i64>(" 0xcafebabe "), Some(3405691582i64));
-    assert_eq!(parse_uint::<i64>(" 0 "), Some(0));
-    assert_eq!(parse_uint::<i64>(" 0x "), None);
-    assert_eq!(parse_uint::<i64>(" 0x1 "), Some(1));
-    assert_eq!(parse_uint::<i64>(" 456hello "), Some(456i64));
-    assert_eq!(parse_uint::<i64>(" -789hello "), None);
+/// Parse int values from a &str, returning the value and the radix used.
+///
+/// Besides decimal numbers, the radix is detected from the prefixes `0x`, `0o` and `0b`,
+/// and `0d` marks a decimal number explicitly.
+pub fn parse_int_auto_with_radix<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Option<(T, u32)> {
+    let options = ParseOptions {
+        radix_prefixes: true,
+        ..Default::default()
+    };
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+
+    parse_uint_internal::<T>(&mut chars, None, prefix.neg, &options).ok()
 }
 
-#[test]
-fn test_parse_uint_base16_i64() {
-    assert_eq!(
-        parse_uint_with_radix::<i64>("CAFEBABE", 16),
-        Some(3405691582i64)
-    );
-    assert_eq!(
-        parse_uint_with_radix::<i64>("  cafebabeyeah", 16),
-        Some(3405691582i64)
-    );
-    assert_eq!(parse_int_with_radix::<i64>("  0xcafebabeyeah", 16), Some(0));
+/// Parse decimal int values from a &str, which must contain the number only.
+///
+/// Whitespace around the number is accepted, so `" 42 "` is valid, but `"42px"` is not.
+pub fn parse_int_strict<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Option<T> {
+    parse_int_from_iter_strict::<T>(&mut s.chars().peekable(), None)
 }
 
-#[test]
-fn test_parse_int_i64() {
-    assert_eq!(parse_int::<i64>("123hello"), Some(123i64));
-    assert_eq!(parse_int::<i64>("    456hello"), Some(456i64));
-    assert_eq!(parse_int::<i64>("  -789hello"), Some(-789i64));
+/// Parse decimal uint values from a &str, returning the remainder behind the number.
+pub fn parse_uint_with_rest<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+) -> Option<(T, &str)> {
+    parse_with_rest(s, |chars| {
+        parse_uint_from_iter_internal::<T>(chars, None, &ParseOptions::default())
+    })
+    .ok()
 }
 
-#[test]
-fn test_parse_int_base16_i64() {
-    assert_eq!(
-        parse_int_with_radix::<i64>("  -CAFEBABE", 16),
-        Some(-3405691582i64)
-    );
-    assert_eq!(
-        parse_int_with_radix::<i64>("  -cafebabeyeah", 16),
-        Some(-3405691582i64)
-    );
-    assert_eq!(
-        parse_int_with_radix::<i64>("  -0xcafebabeyeah", 16),
-        Some(0)
-    );
+/// Parse decimal int values from a &str, returning the remainder behind the number.
+///
+/// Parsing is guaranteed to stop at a decimal point, so the remainder of `"12.34"` is `".34"`
+/// and can be parsed separately.
+pub fn parse_int_with_rest<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Option<(T, &str)> {
+    parse_with_rest(s, |chars| {
+        parse_int_from_iter_internal::<T>(chars, None, &ParseOptions::default())
+    })
+    .ok()
 }
 
-#[test]
-fn test_readme() {
-    assert_eq!(parse_uint::<i32>("+123 as i32 "), Some(123i32));
-    assert_eq!(parse_int::<i32>(" -123 as i32 "), Some(-123i32));
-    assert_eq!(parse_uint::<i64>("+123 as i64 "), Some(123i64));
-    assert_eq!(parse_int::<i64>(" -123 as i64 "), Some(-123i64));
+/// Parse decimal int values from a &str, which may be marked as approximate by a leading `~`.
+///
+/// Returns the value and whether it was marked, so `"~50"` is parsed as `(50, true)`. The
+/// marker must directly precede the sign or digits, so `"~ 50"` is rejected.
+pub fn parse_int_approx<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Option<(T, bool)> {
+    let s = s.trim_start();
 
-    assert_eq!(parse_int::<i64>(" - 1 is invalid "), None);
-    assert_eq!(
-        parse_uint::<u64>(" -123 as u64, parse_int() not available for this type "),
-        None
-    );
-    assert_eq!(
-        parse_uint::<usize>(" 0xcafebabe triggers hex-mode parsing "),
-        Some(3405691582usize)
-    );
+    match s.strip_prefix('~') {
+        Some(s) if s.starts_with(char::is_whitespace) => None,
+        Some(s) => Some((parse_int(s)?, true)),
+        None => Some((parse_int(s)?, false)),
+    }
+}
+
+/// Parse decimal uint values from a &str, reporting the reason on failure.
+pub fn try_parse_uint<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+) -> Result<T, ParseError> {
+    if s.chars().all(char::is_whitespace) {
+        return Err(ParseError::Empty);
+    }
+
+    parse_uint_from_iter_internal::<T>(
+        &mut Replay::new(&mut s.chars().peekable()),
+        None,
+        &ParseOptions::default(),
+    )
+}
+
+/// Parse decimal int values from a &str, reporting the reason on failure.
+pub fn try_parse_int<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Result<T, ParseError> {
+    if s.chars().all(char::is_whitespace) {
+        return Err(ParseError::Empty);
+    }
+
+    parse_int_from_iter_internal::<T>(
+        &mut Replay::new(&mut s.chars().peekable()),
+        None,
+        &ParseOptions::default(),
+    )
+}
+
+/// Check whether a &str is a well-formed int value with a given radix, without parsing it.
+///
+/// Surrounding whitespace is accepted, and a sign when `signed` is set. No radix prefix is
+/// detected. The value isn't accumulated, so any number of digits is valid.
+pub fn is_valid_int(s: &str, radix: u32, signed: bool) -> bool {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    options.parse_prefix(&mut chars, signed);
+
+    if options.scan_digits(&mut chars, radix, |_| {}) == 0 {
+        return false;
+    }
+
+    chars.all(char::is_whitespace)
+}
+
+/// Parse decimal uint values from a &str, saturating on overflow.
+///
+/// Returns the value and whether it overflowed and was saturated to `T::max_value()`.
+/// Invalid input is still rejected.
+pub fn parse_uint_saturating<
+    T: 'static + num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Bounded,
+>(
+    s: &str,
+) -> Option<(T, bool)> {
+    match try_parse_uint::<T>(s) {
+        Ok(ret) => Some((ret, false)),
+        Err(ParseError::Overflow { .. }) => Some((T::max_value(), true)),
+        Err(_) => None,
+    }
+}
+
+/// Parse decimal int values from a &str, saturating on overflow.
+///
+/// Returns the value and whether it overflowed and was saturated to `T::max_value()` or
+/// `T::min_value()`. Invalid input is still rejected.
+pub fn parse_int_saturating<
+    T: 'static
+        + num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::FromPrimitive
+        + num::Signed
+        + num::Bounded,
+>(
+    s: &str,
+) -> Option<(T, bool)> {
+    match try_parse_int::<T>(s) {
+        Ok(ret) => Some((ret, false)),
+        Err(ParseError::Overflow { positive: true }) => Some((T::max_value(), true)),
+        Err(ParseError::Overflow { positive: false }) => Some((T::min_value(), true)),
+        Err(_) => None,
+    }
+}
+
+/// Actions to take when a number overflows its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowAction {
+    /// Saturate the value to the bound of the type it exceeds.
+    Saturate,
+    /// Wrap the value around the bounds of the type.
+    Wrap,
+    /// Reject the value.
+    Reject,
+}
+
+/// Internal function to parse decimal values from a &str, letting a callback decide how to handle
+/// overflow. For unsigned `T`, any non-zero negative value overflows right at its first digit.
+fn parse_with_overflow_internal<
+    T: num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::CheckedSub
+        + num::FromPrimitive
+        + num::Bounded
+        + num::traits::WrappingAdd
+        + num::traits::WrappingMul
+        + num::traits::WrappingSub,
+>(
+    s: &str,
+    on_overflow: impl FnOnce() -> OverflowAction,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let (radix, any) = parse_radix_internal(&mut chars, None, &options);
+    let mut digits = String::new();
+
+    if options.scan_digits(&mut chars, radix, |dig| digits.push(dig)) == 0 {
+        return any.then(T::zero);
+    }
+
+    let mul = T::from_u32(radix).unwrap();
+    let mut on_overflow = Some(on_overflow);
+    let mut wrap = false;
+    let mut ret = T::zero();
+
+    for dig in digits.chars() {
+        let digit = T::from_u32(dig.to_digit(radix).unwrap()).unwrap();
+
+        if !wrap {
+            let next = ret.checked_mul(&mul).and_then(|ret| {
+                if prefix.neg {
+                    ret.checked_sub(&digit)
+                } else {
+                    ret.checked_add(&digit)
+                }
+            });
+
+            if let Some(next) = next {
+                ret = next;
+                continue;
+            }
+
+            match on_overflow.take().unwrap()() {
+                OverflowAction::Saturate if prefix.neg => return Some(T::min_value()),
+                OverflowAction::Saturate => return Some(T::max_value()),
+                OverflowAction::Wrap => wrap = true,
+                OverflowAction::Reject => return None,
+            }
+        }
+
+        ret = ret.wrapping_mul(&mul);
+        ret = if prefix.neg {
+            ret.wrapping_sub(&digit)
+        } else {
+            ret.wrapping_add(&digit)
+        };
+    }
+
+    Some(ret)
+}
+
+/// Parse decimal int values from a &str, letting a callback decide how to handle overflow.
+///
+/// `on_overflow` is called once, the moment the accumulated value overflows `T`.
+pub fn parse_int_with_overflow<
+    T: num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::CheckedSub
+        + num::FromPrimitive
+        + num::Signed
+        + num::Bounded
+        + num::traits::WrappingAdd
+        + num::traits::WrappingMul
+        + num::traits::WrappingSub,
+>(
+    s: &str,
+    on_overflow: impl FnOnce() -> OverflowAction,
+) -> Option<T> {
+    parse_with_overflow_internal(s, on_overflow)
+}
+
+/// Parse decimal uint values from a &str, letting a callback decide how to handle overflow.
+///
+/// Negative values are accepted and treated as overflowing below zero, so with `"-1"`:
+///
+/// - [`OverflowAction::Reject`] returns `None`, like [`parse_uint`] does,
+/// - [`OverflowAction::Saturate`] returns `0`,
+/// - [`OverflowAction::Wrap`] returns the two's complement, e.g. `u32::MAX`.
+///
+/// `"-0"` is `0` and does not call `on_overflow`.
+pub fn parse_uint_with_overflow<
+    T: num::Integer
+        + num::CheckedAdd
+        + num::CheckedMul
+        + num::CheckedSub
+        + num::FromPrimitive
+        + num::Bounded
+        + num::traits::WrappingAdd
+        + num::traits::WrappingMul
+        + num::traits::WrappingSub,
+>(
+    s: &str,
+    on_overflow: impl FnOnce() -> OverflowAction,
+) -> Option<T> {
+    parse_with_overflow_internal(s, on_overflow)
+}
+
+/// Parse decimal int values from a &str within the bounds `min` and `max`.
+///
+/// Values outside the bounds are rejected, or clamped to the nearest bound when `clamp` is set.
+/// Values overflowing `T` are always rejected.
+pub fn parse_int_bounded<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+    min: T,
+    max: T,
+    clamp: bool,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let (ret, _) = parse_uint_internal::<T>(&mut chars, None, prefix.neg, &options).ok()?;
+
+    if ret < min {
+        clamp.then_some(min)
+    } else if ret > max {
+        clamp.then_some(max)
+    } else {
+        Some(ret)
+    }
+}
+
+/// Internal function to parse decimal int values with a number of digits between `min` and `max`.
+fn parse_digit_range_internal<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+    min: u32,
+    max: u32,
+    signed: bool,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, signed);
+    let (ret, count) = parse_digits_internal::<T>(&mut chars, 10, prefix.neg, &options).ok()?;
+
+    if count < min || count > max || !options.parse_suffix(&mut chars, &prefix) {
+        return None;
+    }
+
+    Some(ret)
+}
+
+/// Parse decimal uint values from a &str with a number of digits between `min` and `max`.
+///
+/// Leading zeros count as digits, so `"0042"` has four digits. No radix prefix is detected.
+pub fn parse_uint_digit_range<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive,
+>(
+    s: &str,
+    min: u32,
+    max: u32,
+) -> Option<T> {
+    parse_digit_range_internal(s, min, max, false)
+}
+
+/// Parse decimal int values from a &str with a number of digits between `min` and `max`.
+///
+/// Works like parse_uint_digit_range(), but accepts a sign, which doesn't count as digit.
+pub fn parse_int_digit_range<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+    min: u32,
+    max: u32,
+) -> Option<T> {
+    parse_digit_range_internal(s, min, max, true)
+}
+
+/// Normalize a decimal int value from a &str into its canonical digits.
+///
+/// Apart from surrounding whitespace, the &str must contain the number only. The result consists
+/// of an optional minus sign and the digits without leading zeros, so it is not limited by the
+/// range of any numeric type.
+pub fn normalize_int_digits(s: &str) -> Option<String> {
+    normalize_int_digits_with_options(s, &ParseOptions::default())
+}
+
+/// Normalize a possibly grouped decimal int value from a &str into its canonical digits.
+///
+/// Works like normalize_int_digits(), but the sign and grouping are validated by the options,
+/// so with `grouping: Some(',')`, `"-1,234"` is normalized to `"-1234"`.
+pub fn normalize_int_digits_with_options(s: &str, options: &ParseOptions) -> Option<String> {
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let mut digits = String::new();
+
+    if options.scan_digits(&mut chars, 10, |dig| digits.push(dig)) == 0
+        || !options.parse_suffix(&mut chars, &prefix)
+        || chars.any(|ch| !ch.is_whitespace())
+    {
+        return None;
+    }
+
+    match digits.trim_start_matches('0') {
+        "" => Some("0".to_string()),
+        digits if prefix.neg => Some(format!("-{}", digits)),
+        digits => Some(digits.to_string()),
+    }
+}
+
+/// Parse decimal int values from a fixed-width field of a &str.
+///
+/// Exactly `width` characters are consumed, which must all be digits after an optional sign.
+/// Leading zeros are accepted. Returns the parsed value and the remainder behind the field.
+pub fn parse_int_fixed<T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive>(
+    s: &str,
+    width: usize,
+) -> Option<(T, &str)> {
+    let end = match s.char_indices().nth(width) {
+        Some((end, _)) => end,
+        None if s.chars().count() == width => s.len(),
+        None => return None,
+    };
+
+    let (field, rest) = s.split_at(end);
+    let mut chars = field.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let mut neg = false;
+
+    if let Some(ch) = chars.peek() {
+        if *ch == '+' || *ch == '-' {
+            neg = *ch == '-';
+            chars.next();
+        }
+    }
+
+    let (ret, _) =
+        parse_uint_internal::<T>(&mut chars, Some(10), neg, &ParseOptions::default()).ok()?;
+
+    // The whole field must be made of digits
+    if chars.next().is_some() {
+        return None;
+    }
+
+    Some((ret, rest))
+}
+
+/// Internal function to parse a decimal int value from a field with a sign column.
+fn parse_int_signed_field_internal<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+    blank: bool,
+) -> Option<T> {
+    let mut chars = s.chars();
+
+    let neg = match chars.next()? {
+        '-' => true,
+        '+' => false,
+        ' ' if blank => false,
+        _ => return None,
+    };
+
+    let field = chars.as_str();
+
+    // The remaining columns must all be digits
+    if field.is_empty() || !field.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    let (ret, _) = parse_digits_internal::<T>(
+        &mut Replay::new(&mut field.chars().peekable()),
+        10,
+        neg,
+        &ParseOptions::default(),
+    )
+    .ok()?;
+
+    Some(ret)
+}
+
+/// Parse decimal int values from a field with a sign in the first column, like `"-0007"`.
+///
+/// The sign column holds `+`, `-` or a blank for positive values, and the remaining columns
+/// must all be digits, so zero padding is accepted.
+pub fn parse_int_signed_field<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Option<T> {
+    parse_int_signed_field_internal(s, true)
+}
+
+/// Parse decimal int values from a field with a sign in the first column, requiring the sign.
+///
+/// Works like parse_int_signed_field(), but rejects a blank sign column.
+pub fn parse_int_signed_field_strict<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::FromPrimitive + num::Signed,
+>(
+    s: &str,
+) -> Option<T> {
+    parse_int_signed_field_internal(s, false)
+}
+
+/// Internal function to parse a decimal number from a &str into a scaled integer.
+fn parse_fixed_internal<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::CheckedSub + num::FromPrimitive,
+>(
+    s: &str,
+    scale: u32,
+    round: bool,
+) -> Option<T> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let ten = T::from_u32(10).unwrap();
+
+    // Negative values are accumulated negatively, so the minimum of a signed type is reached
+    let add = |ret: T, digit: u32| {
+        let digit = T::from_u32(digit).unwrap();
+
+        if prefix.neg {
+            ret.checked_sub(&digit)
+        } else {
+            ret.checked_add(&digit)
+        }
+    };
+
+    let (mut ret, mut count) =
+        match parse_digits_internal::<T>(&mut chars, 10, prefix.neg, &options) {
+            Ok((ret, count)) => (ret, count),
+            Err(ParseError::InvalidDigit) => (T::zero(), 0),
+            Err(_) => return None,
+        };
+
+    let mut frac = 0;
+
+    if let Some('.') = chars.peek() {
+        chars.next();
+
+        while let Some(digit) = chars.peek().and_then(|dig| dig.to_digit(10)) {
+            chars.next();
+            count += 1;
+
+            if frac < scale {
+                ret = add(ret.checked_mul(&ten)?, digit)?;
+                frac += 1;
+            } else if !round {
+                return None;
+            } else {
+                // Round half away from zero by the first exceeding digit, skip the others.
+                if digit >= 5 {
+                    ret = add(ret, 1)?;
+                }
+
+                while chars.peek().is_some_and(|dig| dig.is_ascii_digit()) {
+                    chars.next();
+                }
+            }
+        }
+    }
+
+    if count == 0 {
+        return None;
+    }
+
+    while frac < scale {
+        ret = ret.checked_mul(&ten)?;
+        frac += 1;
+    }
+
+    Some(ret)
+}
+
+/// Parse a decimal fixed-point value from a &str, scaled by 10 to the power of `scale`.
+///
+/// `parse_fixed::<i64>("12.34", 2)` results in `1234`, the value in hundredths.
+/// Values with more than `scale` fractional digits are rejected.
+pub fn parse_fixed<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::CheckedSub + num::FromPrimitive,
+>(
+    s: &str,
+    scale: u32,
+) -> Option<T> {
+    parse_fixed_internal(s, scale, false)
+}
+
+/// Parse a decimal fixed-point value from a &str, scaled by 10 to the power of `scale`.
+///
+/// Works like parse_fixed(), but rounds values with more than `scale` fractional digits
+/// half away from zero.
+pub fn parse_fixed_rounded<
+    T: num::Integer + num::CheckedAdd + num::CheckedMul + num::CheckedSub + num::FromPrimitive,
+>(
+    s: &str,
+    scale: u32,
+) -> Option<T> {
+    parse_fixed_internal(s, scale, true)
+}
+
+/// Parse a hex field of `bits` bits from a &str, reinterpreted as two's complement.
+///
+/// The field may be narrower than `T`, so `parse_hex_field::<i32>("ff", 8)` is `-1`.
+/// Only hex digits are accepted. Values exceeding `bits` bits, or not fitting into `T`,
+/// are rejected, as is a width of 0 or more than 128 bits.
+pub fn parse_hex_field<T: num::Integer + num::FromPrimitive + num::Signed>(
+    s: &str,
+    bits: u32,
+) -> Option<T> {
+    if bits == 0 || bits > 128 {
+        return None;
+    }
+
+    let mut chars = s.chars().peekable();
+    let (value, _) = parse_digits(&mut chars, 16)?;
+
+    if chars.peek().is_some() || (bits < 128 && value >> bits != 0) {
+        return None;
+    }
+
+    // Move the field's sign bit to the top, and extend it back by an arithmetic shift
+    let shift = 128 - bits;
+    T::from_i128(((value << shift) as i128) >> shift)
+}
+
+/// Parse decimal int values from a &str into a `num::BigInt`, reusing a buffer for the digits.
+///
+/// The buffer is cleared and filled with the digit values before they're converted at once,
+/// so parsing many numbers with the same buffer only allocates when it has to grow.
+/// The radix is detected from a `0x` prefix, like for parse_int().
+pub fn parse_bigint_with_buffer(s: &str, buffer: &mut Vec<u8>) -> Option<num::BigInt> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let (radix, any) = parse_radix_internal(&mut chars, None, &options);
+
+    buffer.clear();
+
+    while let Some(dig) = chars.peek().and_then(|ch| ch.to_digit(radix)) {
+        buffer.push(dig as u8);
+        chars.next();
+    }
+
+    if buffer.is_empty() {
+        if !any {
+            return None;
+        }
+
+        buffer.push(0);
+    }
+
+    if !options.parse_suffix(&mut chars, &prefix) {
+        return None;
+    }
+
+    let sign = if prefix.neg {
+        num::bigint::Sign::Minus
+    } else {
+        num::bigint::Sign::Plus
+    };
+
+    num::BigInt::from_radix_be(sign, buffer, radix)
+}
+
+/// Parse decimal int values from a &str into a `num::BigInt`.
+///
+/// Use parse_bigint_with_buffer() to avoid an allocation per call when parsing many numbers.
+pub fn parse_bigint(s: &str) -> Option<num::BigInt> {
+    parse_bigint_with_buffer(s, &mut Vec::new())
+}
+
+/// Int value which is promoted to a `num::BigInt` when it doesn't fit into an `i64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntOrBig {
+    /// The value fits into an `i64`.
+    Small(i64),
+    /// The value exceeds the range of an `i64`.
+    Big(num::BigInt),
+}
+
+/// Parse decimal int values from a &str into an `i64`, or a `num::BigInt` on overflow.
+///
+/// Like the integers of Python or Ruby, values are only promoted when needed. The digits are
+/// kept while accumulating, so on the first overflow, they are converted as a whole.
+pub fn parse_int_or_big(s: &str) -> Option<IntOrBig> {
+    let options = ParseOptions::default();
+    let mut chars = s.chars().peekable();
+    let mut chars = Replay::new(&mut chars);
+    let prefix = options.parse_prefix(&mut chars, true);
+    let (radix, any) = parse_radix_internal(&mut chars, None, &options);
+    let mut digits = Vec::new();
+    let mut small = Some(0i64);
+
+    while let Some(dig) = chars.peek().and_then(|ch| ch.to_digit(radix)) {
+        digits.push(dig as u8);
+        chars.next();
+
+        small = small.and_then(|ret| {
+            let ret = ret.checked_mul(radix as i64)?;
+
+            if prefix.neg {
+                ret.checked_sub(dig as i64)
+            } else {
+                ret.checked_add(dig as i64)
+            }
+        });
+    }
+
+    if (digits.is_empty() && !any) || !options.parse_suffix(&mut chars, &prefix) {
+        return None;
+    }
+
+    match small {
+        Some(ret) => Some(IntOrBig::Small(ret)),
+        None => {
+            let sign = if prefix.neg {
+                num::bigint::Sign::Minus
+            } else {
+                num::bigint::Sign::Plus
+            };
+
+            num::BigInt::from_radix_be(sign, &digits, radix).map(IntOrBig::Big)
+        }
+    }
+}
+
+#[test]
+fn test_parse_uint_i64() {
+    assert_eq!(parse_uint::<i64>(" 123hello "), Some(123i64));
+    assert_eq!(parse_uint::<i64>(" 0xcafebabe "), Some(3405691582i64));
+    assert_eq!(parse_uint::<i64>(" 0 "), Some(0));
+    assert_eq!(parse_uint::<i64>(" 0x "), None);
+    assert_eq!(parse_uint::<i64>(" 0x1 "), Some(1));
+    assert_eq!(parse_uint::<i64>(" 456hello "), Some(456i64));
+    assert_eq!(parse_uint::<i64>(" -789hello "), None);
+}
+
+#[test]
+fn test_parse_uint_base16_i64() {
+    assert_eq!(
+        parse_uint_with_radix::<i64>("CAFEBABE", 16),
+        Some(3405691582i64)
+    );
+    assert_eq!(
+        parse_uint_with_radix::<i64>("  cafebabeyeah", 16),
+        Some(3405691582i64)
+    );
+    assert_eq!(parse_int_with_radix::<i64>("  0xcafebabeyeah", 16), Some(0));
+}
+
+#[test]
+fn test_parse_int_i64() {
+    assert_eq!(parse_int::<i64>("123hello"), Some(123i64));
+    assert_eq!(parse_int::<i64>("    456hello"), Some(456i64));
+    assert_eq!(parse_int::<i64>("  -789hello"), Some(-789i64));
+}
+
+#[test]
+fn test_parse_int_base16_i64() {
+    assert_eq!(
+        parse_int_with_radix::<i64>("  -CAFEBABE", 16),
+        Some(-3405691582i64)
+    );
+    assert_eq!(
+        parse_int_with_radix::<i64>("  -cafebabeyeah", 16),
+        Some(-3405691582i64)
+    );
+    assert_eq!(
+        parse_int_with_radix::<i64>("  -0xcafebabeyeah", 16),
+        Some(0)
+    );
+}
+
+#[test]
+fn test_parse_int_sign_whitespace() {
+    // Whitespace is only skipped in front of the sign, never between sign and digits.
+    assert_eq!(parse_int::<i64>("- 5"), None);
+    assert_eq!(parse_int::<i64>("-\t5"), None);
+    assert_eq!(parse_int::<i64>("+ 5"), None);
+    assert_eq!(parse_uint::<u64>("+ 5"), None);
+    assert_eq!(parse_uint::<u64>("+\t5"), None);
+    assert_eq!(parse_int::<i64>(" \t-5"), Some(-5));
+}
+
+#[test]
+fn test_parse_digits() {
+    let mut chars = "0042-rest".chars().peekable();
+    assert_eq!(parse_digits(&mut chars, 10), Some((42, 4)));
+    assert_eq!(chars.next(), Some('-'));
+
+    assert_eq!(
+        parse_digits(&mut "ff".chars().peekable(), 16),
+        Some((255, 2))
+    );
+    assert_eq!(parse_digits(&mut "-1".chars().peekable(), 10), None);
+    assert_eq!(parse_digits(&mut " 1".chars().peekable(), 10), None);
+    assert_eq!(parse_digits(&mut "".chars().peekable(), 10), None);
+    assert_eq!(
+        parse_digits(
+            &mut "340282366920938463463374607431768211455".chars().peekable(),
+            10
+        ),
+        Some((u128::MAX, 39))
+    );
+    assert_eq!(
+        parse_digits(
+            &mut "340282366920938463463374607431768211456".chars().peekable(),
+            10
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_parse_int_overflow() {
+    assert_eq!(parse_uint::<u8>("255"), Some(255));
+    assert_eq!(parse_uint::<u8>("256"), None);
+    assert_eq!(parse_int::<i8>("127"), Some(127));
+    assert_eq!(parse_int::<i8>("-128"), Some(-128));
+    assert_eq!(parse_int::<i8>("-129"), None);
+    assert_eq!(parse_uint::<u8>("0256"), None);
+}
+
+#[test]
+fn test_try_parse_int_peek() {
+    let options = ParseOptions::default();
+
+    let mut chars = "-x".chars().peekable();
+    assert_eq!(
+        try_parse_int_peek::<i32, _>(&mut chars, None, &options),
+        Err(ParseError::InvalidDigit)
+    );
+    assert_eq!(chars.collect::<String>(), "-x");
+
+    let mut chars = " 0x".chars().peekable();
+    assert_eq!(
+        try_parse_uint_peek::<u32, _>(&mut chars, None, &options),
+        Err(ParseError::InvalidDigit)
+    );
+    assert_eq!(chars.collect::<String>(), " 0x");
+
+    let mut chars = "300,1".chars().peekable();
+    assert_eq!(
+        try_parse_uint_peek::<u8, _>(&mut chars, None, &options),
+        Err(ParseError::Overflow { positive: true })
+    );
+    assert_eq!(chars.collect::<String>(), "300,1");
+
+    let options = ParseOptions {
+        grouping: Some(','),
+        group_size: Some(3),
+        ..Default::default()
+    };
+
+    let mut chars = "-1,234,5x".chars().peekable();
+    assert_eq!(
+        try_parse_int_peek::<i32, _>(&mut chars, None, &options),
+        Ok(-1234)
+    );
+    assert_eq!(chars.collect::<String>(), ",5x");
+}
+
+#[test]
+fn test_reject_zero() {
+    let options = ParseOptions {
+        reject_zero: true,
+        ..Default::default()
+    };
+
+    for s in ["0", "-0", "+0", "000", "0x0"] {
+        assert_eq!(
+            parse_int_from_iter_with_options::<i32>(&mut s.chars().peekable(), None, &options),
+            None,
+            "{s}"
+        );
+    }
+
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "0".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "+0".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "1".chars().peekable(), None, &options),
+        Some(1)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "-10".chars().peekable(), None, &options),
+        Some(-10)
+    );
+    assert_eq!(parse_uint::<u32>("0"), Some(0));
+}
+
+#[test]
+fn test_is_valid_int() {
+    assert!(is_valid_int("42", 10, false));
+    assert!(is_valid_int(" -42 ", 10, true));
+    assert!(is_valid_int("+42", 10, false));
+    assert!(is_valid_int("ff", 16, false));
+    assert!(is_valid_int(&"9".repeat(100), 10, true));
+    assert!(!is_valid_int("-42", 10, false));
+    assert!(!is_valid_int("ff", 10, false));
+    assert!(!is_valid_int("0xff", 16, false));
+    assert!(!is_valid_int("42x", 10, true));
+    assert!(!is_valid_int("4 2", 10, true));
+    assert!(!is_valid_int("-", 10, true));
+    assert!(!is_valid_int("", 10, true));
+}
+
+#[test]
+fn test_try_parse_int() {
+    assert_eq!(try_parse_int::<i32>(" -42 "), Ok(-42));
+    assert_eq!(try_parse_uint::<u32>("42"), Ok(42));
+    assert_eq!(try_parse_int::<i32>(""), Err(ParseError::Empty));
+    assert_eq!(try_parse_int::<i32>("   "), Err(ParseError::Empty));
+    assert_eq!(try_parse_uint::<u32>(" \t\n"), Err(ParseError::Empty));
+    assert_eq!(try_parse_int::<i32>("abc"), Err(ParseError::InvalidDigit));
+    assert_eq!(try_parse_int::<i32>("-"), Err(ParseError::InvalidDigit));
+    assert_eq!(try_parse_int::<i32>("0x"), Err(ParseError::InvalidDigit));
+    assert_eq!(try_parse_uint::<u32>("-1"), Err(ParseError::InvalidDigit));
+    assert_eq!(
+        try_parse_uint::<u8>("256"),
+        Err(ParseError::Overflow { positive: true })
+    );
+    assert_eq!(
+        try_parse_int::<i8>("200"),
+        Err(ParseError::Overflow { positive: true })
+    );
+    assert_eq!(
+        try_parse_int::<i8>("-200"),
+        Err(ParseError::Overflow { positive: false })
+    );
+}
+
+#[test]
+fn test_parse_int_value() {
+    assert_eq!(parse_int_value("-42", true), Some(IntValue::Signed(-42)));
+    assert_eq!(parse_int_value("42", false), Some(IntValue::Unsigned(42)));
+    assert_eq!(parse_int_value("-42", false), None);
+    assert_eq!(
+        parse_int_value("340282366920938463463374607431768211455", false),
+        Some(IntValue::Unsigned(u128::MAX))
+    );
+    assert_eq!(
+        parse_int_value("340282366920938463463374607431768211455", true),
+        None
+    );
+    assert_eq!(
+        parse_int_value("340282366920938463463374607431768211456", false),
+        None
+    );
+}
+
+#[test]
+fn test_parse_int_bounded() {
+    assert_eq!(parse_int_bounded::<u32>("42%", 0, 100, false), Some(42));
+    assert_eq!(parse_int_bounded::<u32>("100", 0, 100, false), Some(100));
+    assert_eq!(parse_int_bounded::<u32>("101", 0, 100, false), None);
+    assert_eq!(parse_int_bounded::<u32>("101", 0, 100, true), Some(100));
+    assert_eq!(parse_int_bounded::<i32>("-5", 0, 100, false), None);
+    assert_eq!(parse_int_bounded::<i32>("-5", 0, 100, true), Some(0));
+    assert_eq!(parse_int_bounded::<i32>("-5", -10, 10, false), Some(-5));
+    assert_eq!(parse_int_bounded::<u8>("300", 0, 100, true), None);
+    assert_eq!(
+        parse_int_bounded::<i8>("-128", -128, 127, false),
+        Some(-128)
+    );
+    assert_eq!(parse_int_bounded::<i8>("-129", -128, 127, true), None);
+    assert_eq!(parse_int_bounded::<u8>("-1", 0, 100, true), None);
+    assert_eq!(parse_int_bounded::<u8>("-0", 0, 100, false), Some(0));
+    assert_eq!(parse_int_bounded::<u32>("x", 0, 100, true), None);
+}
+
+#[test]
+fn test_normalize_int_digits() {
+    assert_eq!(
+        normalize_int_digits(" 000123456789012345678901234567890123456789 "),
+        Some("123456789012345678901234567890123456789".to_string())
+    );
+    assert_eq!(normalize_int_digits("+42"), Some("42".to_string()));
+    assert_eq!(normalize_int_digits("-0042"), Some("-42".to_string()));
+    assert_eq!(normalize_int_digits("-000"), Some("0".to_string()));
+    assert_eq!(normalize_int_digits("42abc"), None);
+    assert_eq!(normalize_int_digits("4 2"), None);
+    assert_eq!(normalize_int_digits("-"), None);
+    assert_eq!(normalize_int_digits(""), None);
+    assert_eq!(normalize_int_digits("1,234"), None);
+
+    let options = ParseOptions::from(Locale::EnUs);
+    let normalize = |s: &str| normalize_int_digits_with_options(s, &options);
+
+    assert_eq!(normalize("-1,234,567"), Some("-1234567".to_string()));
+    assert_eq!(normalize(" 0,001 "), Some("1".to_string()));
+    assert_eq!(normalize("1234"), Some("1234".to_string()));
+    assert_eq!(normalize("1,23"), None);
+    assert_eq!(normalize("1,234,"), None);
+    assert_eq!(normalize(",234"), None);
+}
+
+#[test]
+fn test_parse_fixed() {
+    assert_eq!(parse_fixed::<i64>("12.34", 2), Some(1234));
+    assert_eq!(parse_fixed::<i64>("12.3", 2), Some(1230));
+    assert_eq!(parse_fixed::<i64>(" -12", 2), Some(-1200));
+    assert_eq!(parse_fixed::<i64>("-.05", 2), Some(-5));
+    assert_eq!(parse_fixed::<i64>("12.", 2), Some(1200));
+    assert_eq!(parse_fixed::<u32>("0.01", 2), Some(1));
+    assert_eq!(parse_fixed::<i64>("12.345", 2), None);
+    assert_eq!(parse_fixed::<i64>(".", 2), None);
+    assert_eq!(parse_fixed::<i8>("1.28", 2), None);
+    assert_eq!(parse_fixed::<u32>("-1.00", 2), None);
+    assert_eq!(parse_fixed::<u32>("-0.00", 2), Some(0));
+    assert_eq!(parse_fixed::<u32>("-0.01", 2), None);
+    assert_eq!(
+        parse_fixed::<i64>("-92233720368547758.08", 2),
+        Some(i64::MIN)
+    );
+    assert_eq!(parse_fixed::<i64>("-92233720368547758.09", 2), None);
+    assert_eq!(parse_fixed::<i8>("-1.28", 2), Some(-128));
+    assert_eq!(parse_fixed::<i8>("-12", 1), Some(-120));
+
+    assert_eq!(parse_fixed_rounded::<i64>("12.345", 2), Some(1235));
+    assert_eq!(parse_fixed_rounded::<i64>("12.3449", 2), Some(1234));
+    assert_eq!(parse_fixed_rounded::<i64>("-12.345", 2), Some(-1235));
+    assert_eq!(parse_fixed_rounded::<i64>("0.999", 2), Some(100));
+    assert_eq!(parse_fixed_rounded::<i8>("-1.275", 2), Some(-128));
+    assert_eq!(parse_fixed_rounded::<i8>("-1.285", 2), None);
+}
+
+#[test]
+fn test_parse_int_fixed() {
+    assert_eq!(parse_int_fixed::<u32>("00042", 5), Some((42, "")));
+    assert_eq!(parse_int_fixed::<u32>("0004212345", 5), Some((42, "12345")));
+    assert_eq!(parse_int_fixed::<i32>("-0042rest", 5), Some((-42, "rest")));
+    assert_eq!(parse_int_fixed::<i32>("+0042", 5), Some((42, "")));
+    assert_eq!(parse_int_fixed::<u32>("-0042", 5), None);
+    assert_eq!(parse_int_fixed::<u32>("-000", 4), Some((0, "")));
+    assert_eq!(parse_int_fixed::<i8>("-128", 4), Some((-128, "")));
+    assert_eq!(parse_int_fixed::<i8>("-129", 4), None);
+    assert_eq!(parse_int_fixed::<i8>("+128", 4), None);
+    assert_eq!(parse_int_fixed::<u32>("00 42", 5), None);
+    assert_eq!(parse_int_fixed::<u32>(" 0042", 5), None);
+    assert_eq!(parse_int_fixed::<u32>("0042", 5), None);
+    assert_eq!(parse_int_fixed::<u32>("-", 1), None);
+    assert_eq!(parse_int_fixed::<u32>("", 0), None);
+}
+
+#[test]
+fn test_parse_int_signed_field() {
+    assert_eq!(parse_int_signed_field::<i32>("-0007"), Some(-7));
+    assert_eq!(parse_int_signed_field::<i32>("+0042"), Some(42));
+    assert_eq!(parse_int_signed_field::<i32>(" 0042"), Some(42));
+    assert_eq!(parse_int_signed_field::<i8>("-128"), Some(-128));
+    assert_eq!(parse_int_signed_field::<i8>("+128"), None);
+    assert_eq!(parse_int_signed_field::<i32>("0042"), None);
+    assert_eq!(parse_int_signed_field::<i32>("- 042"), None);
+    assert_eq!(parse_int_signed_field::<i32>("+"), None);
+    assert_eq!(parse_int_signed_field::<i32>(""), None);
+
+    assert_eq!(parse_int_signed_field_strict::<i32>("-0007"), Some(-7));
+    assert_eq!(parse_int_signed_field_strict::<i32>(" 0042"), None);
+}
+
+#[test]
+fn test_parse_int_sign_options() {
+    let options = ParseOptions {
+        neg_sign: 'N',
+        pos_sign: Some('P'),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "N42".chars().peekable(), None, &options),
+        Some(-42)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut " P42".chars().peekable(), None, &options),
+        Some(42)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "-42".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "P42".chars().peekable(), None, &options),
+        Some(42)
+    );
+
+    let options = ParseOptions {
+        pos_sign: None,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "+42".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "-42".chars().peekable(), None, &options),
+        Some(-42)
+    );
+}
+
+#[test]
+fn test_parse_int_terminators() {
+    let options = ParseOptions {
+        terminators: Some(vec![';', ',']),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "-12;".chars().peekable(), None, &options),
+        Some(-12)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "12".chars().peekable(), None, &options),
+        Some(12)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "12a,".chars().peekable(), None, &options),
+        None
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "12,".chars().peekable(), None, &options),
+        Some(12)
+    );
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "12 ".chars().peekable(), None, &options),
+        None
+    );
+}
+
+#[test]
+fn test_parse_int_twos_complement() {
+    let options = ParseOptions {
+        radix_twos_complement: true,
+        ..Default::default()
+    };
+
+    let parse_i8 = |s: &str, radix| {
+        parse_int_from_iter_with_options::<i8>(&mut s.chars().peekable(), radix, &options)
+    };
+
+    assert_eq!(parse_int::<i8>("0xFF"), None);
+    assert_eq!(parse_i8("0xFF", None), Some(-1));
+    assert_eq!(parse_i8("0x80", None), Some(-128));
+    assert_eq!(parse_i8("0x7F", None), Some(127));
+    assert_eq!(parse_i8("11111110", Some(2)), Some(-2));
+    assert_eq!(parse_i8("0x100", None), None);
+    assert_eq!(parse_i8("-0xFF", None), None);
+    assert_eq!(parse_i8("255", None), None);
+    assert_eq!(parse_i8("-128", Some(10)), Some(-128));
+    assert_eq!(parse_i8("-129", Some(10)), None);
+
+    assert_eq!(
+        parse_int_from_iter_with_options::<i64>(
+            &mut "0xFFFFFFFFFFFFFFFF".chars().peekable(),
+            None,
+            &options
+        ),
+        Some(-1)
+    );
+    assert_eq!(
+        parse_int_from_iter_with_options::<i128>(
+            &mut "0x80000000000000000000000000000000".chars().peekable(),
+            None,
+            &options
+        ),
+        Some(i128::MIN)
+    );
+}
+
+#[test]
+fn test_parse_int_grouping() {
+    let options = ParseOptions {
+        grouping: Some('\''),
+        ..Default::default()
+    };
+
+    let parse_i64 = |s: &str| {
+        parse_int_from_iter_with_options::<i64>(&mut s.chars().peekable(), None, &options)
+    };
+
+    assert_eq!(parse_int::<i64>("1'234'567"), Some(1));
+    assert_eq!(parse_i64("1'234'567"), Some(1234567));
+    assert_eq!(parse_i64("-1'234"), Some(-1234));
+    assert_eq!(parse_i64("1234"), Some(1234));
+    assert_eq!(parse_i64("'1234"), None);
+    assert_eq!(parse_i64("1''234"), Some(1));
+    assert_eq!(parse_i64("1'"), Some(1));
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut "0x1'f".chars().peekable(), None, &options),
+        Some(31)
+    );
+}
+
+#[test]
+fn test_parse_int_grouping_u128() {
+    let options: ParseOptions = Locale::EnUs.into();
+
+    let parse_u128 = |s: &str| {
+        parse_uint_from_iter_with_options::<u128>(&mut s.chars().peekable(), None, &options)
+    };
+    let parse_i128 = |s: &str| {
+        parse_int_from_iter_with_options::<i128>(&mut s.chars().peekable(), None, &options)
+    };
+
+    assert_eq!(
+        parse_u128("340,282,366,920,938,463,463,374,607,431,768,211,455"),
+        Some(u128::MAX)
+    );
+    assert_eq!(
+        parse_u128("340,282,366,920,938,463,463,374,607,431,768,211,456"),
+        None
+    );
+    assert_eq!(
+        parse_i128("-170,141,183,460,469,231,731,687,303,715,884,105,728"),
+        Some(i128::MIN)
+    );
+}
+
+#[test]
+fn test_readme() {
+    assert_eq!(parse_uint::<i32>("+123 as i32 "), Some(123i32));
+    assert_eq!(parse_int::<i32>(" -123 as i32 "), Some(-123i32));
+    assert_eq!(parse_uint::<i64>("+123 as i64 "), Some(123i64));
+    assert_eq!(parse_int::<i64>(" -123 as i64 "), Some(-123i64));
+
+    assert_eq!(parse_int::<i64>(" - 1 is invalid "), None);
+    assert_eq!(
+        parse_uint::<u64>(" -123 as u64, parse_int() not available for this type "),
+        None
+    );
+    assert_eq!(
+        parse_uint::<usize>(" 0xcafebabe triggers hex-mode parsing "),
+        Some(3405691582usize)
+    );
+}
+
+#[test]
+fn test_parse_u64_const() {
+    const PORT: Option<u64> = parse_u64_const(" 8080");
+    assert_eq!(PORT, Some(8080));
+
+    assert_eq!(parse_u64_const("+42px"), Some(42));
+    assert_eq!(parse_u64_const("18446744073709551615"), Some(u64::MAX));
+    assert_eq!(parse_u64_const("18446744073709551616"), None);
+    assert_eq!(parse_u64_const("-1"), None);
+    assert_eq!(parse_u64_const("+"), None);
+    assert_eq!(parse_u64_const(""), None);
+}
+
+#[test]
+fn test_parse_int_exponent() {
+    let options = ParseOptions {
+        int_exponent: true,
+        ..Default::default()
+    };
+
+    let parse_u32 = |s: &str| {
+        parse_uint_from_iter_with_options::<u32>(&mut s.chars().peekable(), None, &options)
+    };
+    let parse_i8 = |s: &str| {
+        parse_int_from_iter_internal::<i8>(
+            &mut Replay::new(&mut s.chars().peekable()),
+            None,
+            &options,
+        )
+    };
+
+    assert_eq!(parse_uint::<u32>("5e3"), Some(5));
+    assert_eq!(parse_u32("5e3"), Some(5000));
+    assert_eq!(parse_u32("5E0"), Some(5));
+    assert_eq!(parse_u32("0e99999"), Some(0));
+    assert_eq!(parse_u32("5ex"), Some(5));
+    assert_eq!(parse_u32("5e-1"), None);
+    assert_eq!(parse_u32("5e+1"), None);
+    assert_eq!(parse_u32("5e10"), None);
+    assert_eq!(parse_u32("0x5e3"), Some(0x5e3));
+
+    assert_eq!(parse_i8("-1e2"), Ok(-100));
+    assert_eq!(
+        parse_i8("-2e2"),
+        Err(ParseError::Overflow { positive: false })
+    );
+    assert_eq!(
+        parse_i8("2e2"),
+        Err(ParseError::Overflow { positive: true })
+    );
+
+    // The exponent applies to two's complement parsing as well
+    let twos = ParseOptions {
+        radix_twos_complement: true,
+        ..options.clone()
+    };
+    assert_eq!(
+        parse_int_from_iter_with_options::<i32>(&mut "5e3".chars().peekable(), None, &twos),
+        Some(5000)
+    );
+
+    // A dangling exponent marker stays in the caller's iterator
+    let mut chars = Pushback::new("5ex".chars());
+    assert_eq!(
+        parse_uint_from_iter_with_options::<u32>(&mut chars, None, &options),
+        Some(5)
+    );
+    assert_eq!(chars.collect::<String>(), "ex");
+}
+
+#[test]
+fn test_parse_int_with_rest() {
+    assert_eq!(parse_int_with_rest::<i32>("12.34"), Some((12, ".34")));
+    assert_eq!(parse_int_with_rest::<i32>(" -12.34"), Some((-12, ".34")));
+    assert_eq!(parse_int_with_rest::<i32>("42"), Some((42, "")));
+    assert_eq!(parse_int_with_rest::<i32>("0x1fg"), Some((0x1f, "g")));
+    assert_eq!(parse_int_with_rest::<i32>(".34"), None);
+    assert_eq!(parse_uint_with_rest::<u32>("12€"), Some((12, "€")));
+    assert_eq!(parse_uint_with_rest::<u32>("-12"), None);
+}
+
+#[test]
+fn test_parse_int_auto_with_radix() {
+    assert_eq!(parse_int_auto_with_radix::<i32>("42"), Some((42, 10)));
+    assert_eq!(parse_int_auto_with_radix::<i32>("-0x1F"), Some((-31, 16)));
+    assert_eq!(parse_int_auto_with_radix::<i32>("0o17"), Some((15, 8)));
+    assert_eq!(parse_int_auto_with_radix::<i32>(" 0B101 "), Some((5, 2)));
+    assert_eq!(parse_int_auto_with_radix::<i32>("0"), Some((0, 10)));
+    assert_eq!(parse_int_auto_with_radix::<i32>("0b2"), None);
+    assert_eq!(parse_int_auto_with_radix::<i32>("x1"), None);
+    assert_eq!(parse_int_auto_with_radix::<i32>("0d42"), Some((42, 10)));
+    assert_eq!(parse_int_auto_with_radix::<i32>("-0D42"), Some((-42, 10)));
+    assert_eq!(parse_int_auto_with_radix::<i32>("0d"), None);
+    assert_eq!(parse_int_auto_with_radix::<i32>("0dff"), None);
+
+    assert_eq!(parse_int::<i32>("0b101"), Some(0));
+}
+
+#[test]
+fn test_parse_int_strict() {
+    assert_eq!(parse_int_strict::<i32>(" -42 "), Some(-42));
+    assert_eq!(parse_int_strict::<i32>("42px"), None);
+    assert_eq!(parse_int_strict::<i32>("0x1f"), Some(31));
+    assert_eq!(parse_int_strict::<i32>(""), None);
+
+    let mut chars = "ff\t".chars().peekable();
+    assert_eq!(
+        parse_int_from_iter_strict::<i32>(&mut chars, Some(16)),
+        Some(255)
+    );
+
+    let mut chars = "1 2".chars().peekable();
+    assert_eq!(parse_int_from_iter_strict::<i32>(&mut chars, None), None);
+}
+
+#[test]
+fn test_parse_int_saturating() {
+    assert_eq!(parse_int_saturating::<i8>("100"), Some((100, false)));
+    assert_eq!(parse_int_saturating::<i8>("-128"), Some((-128, false)));
+    assert_eq!(parse_int_saturating::<i8>("200"), Some((127, true)));
+    assert_eq!(parse_int_saturating::<i8>(" -99999 "), Some((-128, true)));
+    assert_eq!(parse_int_saturating::<i8>("x"), None);
+    assert_eq!(parse_uint_saturating::<u8>("0x100"), Some((255, true)));
+    assert_eq!(parse_uint_saturating::<u8>("-1"), None);
+}
+
+#[test]
+fn test_parse_int_with_overflow() {
+    let mut called = 0;
+    assert_eq!(
+        parse_int_with_overflow::<i8>("100", || {
+            called += 1;
+            OverflowAction::Reject
+        }),
+        Some(100)
+    );
+    assert_eq!(called, 0);
+
+    assert_eq!(
+        parse_int_with_overflow::<i8>("200", || OverflowAction::Saturate),
+        Some(127)
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i8>("-200", || OverflowAction::Saturate),
+        Some(-128)
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i8>("200", || OverflowAction::Wrap),
+        Some(200u8 as i8)
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i8>("-1000", || OverflowAction::Wrap),
+        Some(-1000i32 as i8)
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i32>("0x1ffffffff", || OverflowAction::Wrap),
+        Some(-1)
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i8>("200", || OverflowAction::Reject),
+        None
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i8>("0", || OverflowAction::Reject),
+        Some(0)
+    );
+    assert_eq!(
+        parse_int_with_overflow::<i8>("x", || OverflowAction::Saturate),
+        None
+    );
+}
+
+#[test]
+fn test_parse_uint_with_overflow() {
+    assert_eq!(
+        parse_uint_with_overflow::<u32>("-1", || OverflowAction::Wrap),
+        Some(u32::MAX)
+    );
+    assert_eq!(
+        parse_uint_with_overflow::<u32>("-1", || OverflowAction::Saturate),
+        Some(0)
+    );
+    assert_eq!(
+        parse_uint_with_overflow::<u32>("-1", || OverflowAction::Reject),
+        None
+    );
+    assert_eq!(
+        parse_uint_with_overflow::<u8>("-0x80", || OverflowAction::Wrap),
+        Some(0x80)
+    );
+    assert_eq!(
+        parse_uint_with_overflow::<u8>("-0", || OverflowAction::Reject),
+        Some(0)
+    );
+    assert_eq!(
+        parse_uint_with_overflow::<u8>("300", || OverflowAction::Wrap),
+        Some(44)
+    );
+    assert_eq!(
+        parse_uint_with_overflow::<u8>("255", || OverflowAction::Reject),
+        Some(255)
+    );
+}
+
+#[test]
+fn test_parse_uint_digit_range() {
+    assert_eq!(parse_uint_digit_range::<u32>("1234", 4, 4), Some(1234));
+    assert_eq!(parse_uint_digit_range::<u32>("0042", 4, 4), Some(42));
+    assert_eq!(parse_uint_digit_range::<u32>("123", 4, 4), None);
+    assert_eq!(parse_uint_digit_range::<u32>("99", 2, 4), Some(99));
+    assert_eq!(
+        parse_uint_digit_range::<u32>(" 1999-01-01", 2, 4),
+        Some(1999)
+    );
+    assert_eq!(parse_uint_digit_range::<u32>("19999", 2, 4), None);
+    assert_eq!(parse_uint_digit_range::<u32>("9", 2, 4), None);
+    assert_eq!(parse_uint_digit_range::<u32>("-12", 2, 4), None);
+    assert_eq!(parse_uint_digit_range::<u32>("0x12", 2, 4), None);
+
+    assert_eq!(parse_int_digit_range::<i32>("-12", 2, 4), Some(-12));
+    assert_eq!(parse_int_digit_range::<i32>("+1234", 2, 4), Some(1234));
+    assert_eq!(parse_int_digit_range::<i32>("-1", 2, 4), None);
+}
+
+#[test]
+fn test_parse_int_approx() {
+    assert_eq!(parse_int_approx::<i32>("~50"), Some((50, true)));
+    assert_eq!(parse_int_approx::<i32>(" ~-50 items"), Some((-50, true)));
+    assert_eq!(parse_int_approx::<i32>("50"), Some((50, false)));
+    assert_eq!(parse_int_approx::<i32>("-~50"), None);
+    assert_eq!(parse_int_approx::<i32>("~~50"), None);
+    assert_eq!(parse_int_approx::<i32>("~"), None);
+    assert_eq!(parse_int_approx::<i32>("~ 50"), None);
+    assert_eq!(parse_int_approx::<i32>(" ~  50"), None);
+    assert_eq!(parse_int_approx::<i32>("~\t-50"), None);
+}
+
+#[test]
+fn test_parse_hex_field() {
+    assert_eq!(parse_hex_field::<i16>("8000", 16), Some(-32768));
+    assert_eq!(parse_hex_field::<i16>("7fff", 16), Some(32767));
+    assert_eq!(parse_hex_field::<i32>("FF", 8), Some(-1));
+    assert_eq!(parse_hex_field::<i32>("7f", 8), Some(127));
+    assert_eq!(parse_hex_field::<i32>("800", 12), Some(-2048));
+    assert_eq!(parse_hex_field::<i8>("f", 4), Some(-1));
+    assert_eq!(parse_hex_field::<i128>(&"f".repeat(32), 128), Some(-1));
+    assert_eq!(
+        parse_hex_field::<i128>(&format!("8{}", "0".repeat(31)), 128),
+        Some(i128::MIN)
+    );
+    assert_eq!(
+        parse_hex_field::<i128>(&format!("7{}", "f".repeat(31)), 128),
+        Some(i128::MAX)
+    );
+    assert_eq!(
+        parse_hex_field::<i128>(&format!("7{}", "f".repeat(31)), 127),
+        Some(-1)
+    );
+    assert_eq!(
+        parse_hex_field::<i128>(&format!("4{}", "0".repeat(31)), 127),
+        Some(i128::MIN >> 1)
+    );
+    assert_eq!(
+        parse_hex_field::<i128>(&format!("3{}", "f".repeat(31)), 127),
+        Some(i128::MAX >> 1)
+    );
+    assert_eq!(
+        parse_hex_field::<i128>(&format!("8{}", "0".repeat(31)), 127),
+        None
+    );
+    assert_eq!(parse_hex_field::<i16>("10000", 16), None);
+    assert_eq!(parse_hex_field::<i16>("100", 8), None);
+    assert_eq!(parse_hex_field::<i8>("7fff", 16), None);
+    assert_eq!(parse_hex_field::<i16>("0x80", 16), None);
+    assert_eq!(parse_hex_field::<i16>("", 16), None);
+    assert_eq!(parse_hex_field::<i16>("1", 0), None);
+}
+
+#[test]
+fn test_parse_bigint() {
+    let big = "-123456789012345678901234567890123456789";
+
+    assert_eq!(parse_bigint(big), big.parse::<num::BigInt>().ok());
+    assert_eq!(parse_bigint(" 42px"), Some(num::BigInt::from(42)));
+    assert_eq!(parse_bigint("0xff"), Some(num::BigInt::from(255)));
+    assert_eq!(parse_bigint("0"), Some(num::BigInt::from(0)));
+    assert_eq!(parse_bigint("-0"), Some(num::BigInt::from(0)));
+    assert_eq!(parse_bigint("-"), None);
+    assert_eq!(parse_bigint("x"), None);
+
+    let mut buffer = Vec::new();
+    assert_eq!(
+        parse_bigint_with_buffer(big, &mut buffer),
+        big.parse::<num::BigInt>().ok()
+    );
+
+    let capacity = buffer.capacity();
+    assert_eq!(
+        parse_bigint_with_buffer("1234", &mut buffer),
+        Some(num::BigInt::from(1234))
+    );
+    assert_eq!(buffer, [1, 2, 3, 4]);
+    assert_eq!(buffer.capacity(), capacity);
+}
+
+#[test]
+fn test_parse_int_or_big() {
+    assert_eq!(parse_int_or_big("42"), Some(IntOrBig::Small(42)));
+    assert_eq!(parse_int_or_big(" -42px"), Some(IntOrBig::Small(-42)));
+    assert_eq!(parse_int_or_big("0"), Some(IntOrBig::Small(0)));
+    assert_eq!(parse_int_or_big("0xff"), Some(IntOrBig::Small(255)));
+    assert_eq!(
+        parse_int_or_big("9223372036854775807"),
+        Some(IntOrBig::Small(i64::MAX))
+    );
+    assert_eq!(
+        parse_int_or_big("-9223372036854775808"),
+        Some(IntOrBig::Small(i64::MIN))
+    );
+    assert_eq!(
+        parse_int_or_big("9223372036854775808"),
+        Some(IntOrBig::Big(num::BigInt::from(i64::MAX) + 1))
+    );
+    assert_eq!(
+        parse_int_or_big("-9223372036854775809"),
+        Some(IntOrBig::Big(num::BigInt::from(i64::MIN) - 1))
+    );
+    assert_eq!(
+        parse_int_or_big("0x10000000000000000"),
+        Some(IntOrBig::Big(num::BigInt::from(u64::MAX) + 1))
+    );
+    assert_eq!(parse_int_or_big("-"), None);
+    assert_eq!(parse_int_or_big("x"), None);
+}
+
+#[test]
+fn test_parse_int_locale() {
+    let parse = |s: &str, locale: Locale| {
+        let options = ParseOptions {
+            terminators: Some(vec![]),
+            ..locale.into()
+        };
+
+        parse_int_from_iter_with_options::<i32>(&mut s.chars().peekable(), None, &options)
+    };
+
+    assert_eq!(parse("-1,234", Locale::EnUs), Some(-1234));
+    assert_eq!(parse("1,23,456", Locale::Indian), Some(123456));
+    assert_eq!(parse("1,234,56", Locale::Indian), None);
 }